@@ -1,4 +1,19 @@
-use crate::data::{Creator, Membership, Transaction, Trophy};
+use crate::data::{
+    Creator, DonationLimits, FundingGoal, FundingProgress, Matching, Membership, Payee, Split,
+    Tier, Transaction, Trophy, Vesting,
+};
+use crate::events::{
+    AcceptedResourceAddedEvent, AcceptedResourceRemovedEvent, AutoPayoutChangedEvent,
+    CampaignSettledEvent, CollectionClosedEvent, CollectionCreatorChangedEvent,
+    CollectionPausedEvent, CollectionResumedEvent, DonationEvent, DonationLimitsChangedEvent,
+    DonationPaidOutEvent, FeeRateChangedEvent, FeeWithdrawalEvent, GiftEvent, MatchingChangedEvent,
+    MatchingPoolFundedEvent, MatchingPoolReclaimedEvent, MembershipMintedEvent,
+    MembershipRenewedEvent, MembershipUpdatedEvent, MilestoneApprovedEvent, RefundEvent,
+    ShareClaimedEvent, SplitsChangedEvent, TiersChangedEvent, TrophiesRestampedEvent,
+    TrophyDetailsUpdatedEvent, TrophyMintedEvent, TrophyUpdatedEvent, VestingSetEvent,
+    WithdrawalEvent,
+};
+use crate::repository::repository::Repository;
 use crate::util::*;
 use scrypto::prelude::*;
 
@@ -12,29 +27,99 @@ pub struct CollectionArg {
     pub repository_owner_access_badge_address: ResourceAddress,
     pub creator_badge_proof: CheckedProof,
     pub minter_badge: Bucket,
-    pub creator_name: String,
-    pub creator_slug: String,
     pub trophy_name: String,
     pub trophy_description: String,
     pub dapp_definition_address: GlobalAddress,
+    pub repository: Global<Repository>,
+    pub fee_rate: Option<Decimal>,
+    pub funding_goal: Option<FundingGoal>,
 }
 
 #[blueprint]
 #[types(Trophy, Membership, Creator, Transaction)]
+#[events(
+    DonationEvent,
+    GiftEvent,
+    TrophyMintedEvent,
+    TrophyUpdatedEvent,
+    TrophyDetailsUpdatedEvent,
+    TrophiesRestampedEvent,
+    MembershipMintedEvent,
+    MembershipUpdatedEvent,
+    MembershipRenewedEvent,
+    CollectionClosedEvent,
+    CollectionPausedEvent,
+    CollectionResumedEvent,
+    CollectionCreatorChangedEvent,
+    WithdrawalEvent,
+    FeeWithdrawalEvent,
+    AutoPayoutChangedEvent,
+    DonationPaidOutEvent,
+    VestingSetEvent,
+    MilestoneApprovedEvent,
+    MatchingChangedEvent,
+    MatchingPoolFundedEvent,
+    MatchingPoolReclaimedEvent,
+    SplitsChangedEvent,
+    ShareClaimedEvent,
+    CampaignSettledEvent,
+    RefundEvent,
+    FeeRateChangedEvent,
+    DonationLimitsChangedEvent,
+    TiersChangedEvent,
+    AcceptedResourceAddedEvent,
+    AcceptedResourceRemovedEvent
+)]
 mod collection {
     enable_method_auth! {
         roles {
             repository_owner => updatable_by: [];
-            owner => updatable_by: [];
+            repository => updatable_by: [];
+            owner => updatable_by: [SELF];
         },
         methods {
             donate_mint => PUBLIC;
+            donate_gift => PUBLIC;
             donate_mint_with_membership => PUBLIC;
             donate_update => PUBLIC;
             donate_update_with_membership => PUBLIC;
+            renew_membership => PUBLIC;
             withdraw_donations => restrict_to: [owner];
             withdraw_fees => restrict_to: [repository_owner];
+            set_matching => restrict_to: [owner];
+            get_matching => PUBLIC;
+            fund_matching_pool => PUBLIC;
+            get_matching_pool => PUBLIC;
+            reclaim_matching_pool => PUBLIC;
+            set_vesting => restrict_to: [owner];
+            get_vesting => PUBLIC;
+            approve_milestone => restrict_to: [repository];
+            get_vesting_balance => PUBLIC;
+            set_auto_payout => restrict_to: [owner];
+            get_auto_payout => PUBLIC;
+            set_splits => restrict_to: [owner];
+            get_splits => PUBLIC;
+            get_split_balance => PUBLIC;
+            claim_share => PUBLIC;
             close_collection => restrict_to: [owner];
+            pause_collection => restrict_to: [owner];
+            admin_pause_collection => restrict_to: [repository_owner];
+            resume_collection => restrict_to: [owner, repository_owner];
+            get_paused => PUBLIC;
+            set_fee_rate => restrict_to: [repository_owner];
+            get_fee_rate => PUBLIC;
+            get_funding_progress => PUBLIC;
+            refund => PUBLIC;
+            add_accepted_resource => restrict_to: [repository_owner];
+            remove_accepted_resource => restrict_to: [repository_owner];
+            set_tiers => restrict_to: [owner];
+            get_tiers => PUBLIC;
+            set_donation_limits => restrict_to: [owner];
+            get_donation_limits => PUBLIC;
+            get_counted_resource => PUBLIC;
+            update_trophy_details => restrict_to: [owner];
+            restamp_trophies => restrict_to: [owner];
+            set_creator => restrict_to: [repository];
         }
     }
 
@@ -54,17 +139,17 @@ mod collection {
         // NFT minter badge
         minter_badge: Vault,
 
-        // Collected donations
-        donations: Vault,
+        // Collected donations, one vault per resource
+        donations: KeyValueStore<ResourceAddress, Vault>,
 
-        // Fees for the donations
-        fees: Vault,
+        // Fees for the donations, one vault per resource
+        fees: KeyValueStore<ResourceAddress, Vault>,
 
-        // Specific user name that owns this component
-        creator_name: String,
+        // Resources that has donation and fee vaults in the collection
+        resources: Vec<ResourceAddress>,
 
-        // Specific user slug that owns this component
-        creator_slug: String,
+        // Resources that are currently accepted as donations
+        accepted_resources: Vec<ResourceAddress>,
 
         // Name of the trophy
         trophy_name: String,
@@ -80,10 +165,95 @@ mod collection {
 
         // Closed date for the collection
         closed: Option<UtcDateTime>,
+
+        // Paused date for the collection, donations are not accepted while paused
+        paused: Option<UtcDateTime>,
+
+        // Whether the collection was paused by the repository owner, which only the repository
+        // owner can resume
+        paused_by_repository_owner: bool,
+
+        // Repository owner access badge address
+        repository_owner_access_badge_address: ResourceAddress,
+
+        // Repository component that instantiated this collection
+        repository: Global<Repository>,
+
+        // Fee rate override for this collection, the repository fee rate is used when not set
+        fee_rate: Option<Decimal>,
+
+        // Optional funding goal the creator is raising towards
+        funding_goal: Option<FundingGoal>,
+
+        // Total amount donated to the collection, including fees
+        raised: Decimal,
+
+        // Whether the funding goal has been reached before its deadline
+        goal_reached: bool,
+
+        // Whether the fees of a successful all-or-nothing campaign has been taken, releasing the
+        // escrowed donations to the creator
+        settled: bool,
+
+        // Donation tiers ordered by ascending thresholds, shaping the trophy a backer receives
+        tiers: Vec<Tier>,
+
+        // Donation limits overrides for this collection per resource, the repository limits are
+        // used for resources without an override
+        donation_limits: KeyValueStore<ResourceAddress, DonationLimits>,
+
+        // Resource that donations are counted in towards the trophies, memberships, thanks tokens
+        // and the amount raised, fixed when the collection is created
+        counted_resource: ResourceAddress,
+
+        // Payees sharing the donations of the collection, the owner withdraws the donations when
+        // there are no payees
+        splits: Vec<Split>,
+
+        // Donations allocated to payees that has not been claimed, per resource
+        allocated: KeyValueStore<ResourceAddress, Decimal>,
+
+        // Donations allocated to a payee that has not been claimed, keyed by the payee
+        split_balances: KeyValueStore<Payee, Vec<(ResourceAddress, Decimal)>>,
+
+        // Account that receives the donations directly when they are made, the donations are
+        // kept in the collection when not set
+        payout_account: Option<Global<Account>>,
+
+        // Vesting that releases the donations progressively, all donations can be withdrawn when
+        // not set
+        vesting: Option<Vesting>,
+
+        // Donations released to the owner or to payees, per resource
+        released: KeyValueStore<ResourceAddress, Decimal>,
+
+        // Matching of the donations from the matching pool, donations are not matched when not
+        // set
+        matching: Option<Matching>,
+
+        // Funds of sponsors that match the donations, one vault per resource
+        matching_pool: KeyValueStore<ResourceAddress, Vault>,
+
+        // Donations matched from the matching pool, per resource
+        matched: KeyValueStore<ResourceAddress, Decimal>,
+
+        // Funds given to the matching pool that has not been reclaimed, keyed by the sponsor badge
+        sponsors: KeyValueStore<NonFungibleGlobalId, Vec<(ResourceAddress, Decimal)>>,
+
+        // Funds given to the matching pool that has not been reclaimed, per resource
+        sponsored: KeyValueStore<ResourceAddress, Decimal>,
     }
 
     impl Collection {
         pub fn new(arg: CollectionArg) -> Global<Collection> {
+            if let Some(fee_rate) = arg.fee_rate {
+                validate_fee_rate(fee_rate);
+            }
+
+            if let Some(funding_goal) = &arg.funding_goal {
+                validate_funding_goal(funding_goal);
+            }
+
             let (reservation, address) =
                 Runtime::allocate_component_address(Collection::blueprint_id());
             let collection_id = Runtime::bech32_encode_address(address);
@@ -95,21 +265,56 @@ mod collection {
                     .non_fungible_local_id(),
             );
 
+            // Donations are counted in the resource of the funding goal, or in XRD without one.
+            let counted_resource = arg
+                .funding_goal
+                .as_ref()
+                .map_or(XRD, |funding_goal| funding_goal.resource_address);
+
+            let donations = KeyValueStore::new();
+            donations.insert(XRD, Vault::new(XRD));
+
+            let fees = KeyValueStore::new();
+            fees.insert(XRD, Vault::new(XRD));
+
             Self {
                 minter_badge: Vault::with_bucket(arg.minter_badge),
-                donations: Vault::new(XRD),
-                fees: Vault::new(XRD),
+                donations,
+                fees,
+                resources: vec![XRD],
+                accepted_resources: vec![XRD],
                 collection_id,
                 creator_badge_global_id: creator_badge_global_id.clone(),
                 trophy_resource_manager: arg.trophy_resource_manager,
                 thanks_token_resource_manager: arg.thanks_token_resource_manager,
                 membership_resource_manager: arg.membership_resource_manager,
                 creator_resource_manager: arg.creator_resource_manager,
-                creator_name: arg.creator_name,
-                creator_slug: arg.creator_slug,
                 trophy_name: arg.trophy_name,
                 trophy_description: arg.trophy_description,
                 closed: None,
+                paused: None,
+                paused_by_repository_owner: false,
+                repository_owner_access_badge_address: arg.repository_owner_access_badge_address,
+                repository: arg.repository,
+                fee_rate: arg.fee_rate,
+                funding_goal: arg.funding_goal,
+                raised: dec!(0),
+                goal_reached: false,
+                settled: false,
+                tiers: vec![],
+                donation_limits: KeyValueStore::new(),
+                counted_resource,
+                splits: vec![],
+                allocated: KeyValueStore::new(),
+                split_balances: KeyValueStore::new(),
+                payout_account: None,
+                vesting: None,
+                released: KeyValueStore::new(),
+                matching: None,
+                matching_pool: KeyValueStore::new(),
+                matched: KeyValueStore::new(),
+                sponsors: KeyValueStore::new(),
+                sponsored: KeyValueStore::new(),
             }
             .instantiate()
             .prepare_to_globalize(OwnerRole::Fixed(rule!(require(
@@ -128,12 +333,30 @@ mod collection {
             ))
             .roles(roles!(
                 repository_owner => rule!(require(arg.repository_owner_access_badge_address));
+                repository => rule!(require(global_caller(arg.repository.address())));
                 owner => rule!(require(creator_badge_global_id.clone()));
             ))
             .with_address(reservation)
             .globalize()
         }
 
+        // is_creator is a private method that returns true if the given creator badge is the creator
+        // of this collection, or has been merged into it.
+        fn is_creator(&self, creator: &NonFungibleGlobalId) -> bool {
+            creator.eq(&self.creator_badge_global_id)
+                || self
+                    .repository
+                    .resolve_creator(creator.clone())
+                    .eq(&self.creator_badge_global_id)
+        }
+
+        // get_creator is a private method that returns the current data of the creator badge that
+        // owns this collection.
+        fn get_creator(&self) -> Creator {
+            self.creator_resource_manager
+                .get_non_fungible_data(self.creator_badge_global_id.local_id())
+        }
+
         // update_creator_metadata is a private method that updates the creator metadata based on
         // the amount donated.
         fn update_creator_metadata(&mut self, amount: Decimal) {
@@ -170,7 +393,7 @@ mod collection {
         }
 
         // mint_membership is a private method that mints a membership NFT based on the amount
-        fn mint_membership(&mut self, donated: Decimal) -> Bucket {
+        fn mint_membership(&mut self, transaction: Transaction) -> Bucket {
             // Get the domain name used from the trophy resource manager.
             let domain: String = self
                 .trophy_resource_manager
@@ -178,22 +401,37 @@ mod collection {
                 .unwrap()
                 .expect("No domain on NFT repository");
 
-            let created = generate_created_string(
-                UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap(),
+            let donated = self.counted_amount(&transaction);
+            let created = transaction.created.clone();
+            let creator = self.get_creator();
+
+            // Memberships of a creator offering subscriptions expire, unless renewed. A donation
+            // below the subscription price mints a membership that has already expired.
+            let expires_at = self
+                .repository
+                .get_subscription(self.creator_badge_global_id.clone())
+                .and_then(|subscription| {
+                    extend_expiry(
+                        Some(Clock::current_time_rounded_to_minutes()),
+                        &subscription,
+                        &transaction,
+                    )
+                });
+
+            let level = resolve_tier(
+                &self
+                    .repository
+                    .get_membership_levels(self.creator_badge_global_id.clone()),
+                donated,
             );
 
-            let transaction = Transaction {
-                amount: donated,
-                created: created.clone(),
-            };
-
             let data = Membership {
-                name: format!("Membership: {}", self.creator_name),
-                description: format!("Digital emblem celebrating {}'s crowdfunding journey. It evolves with cumulative donations. It's a symbol of encouragement, encapsulating the artist-backer bond in the digital age.", self.creator_name).to_string(),
+                name: format!("Membership: {}", creator.creator_name),
+                description: format!("Digital emblem celebrating {}'s crowdfunding journey. It evolves with cumulative donations. It's a symbol of encouragement, encapsulating the artist-backer bond in the digital age.", creator.creator_name).to_string(),
                 creator: self.creator_badge_global_id.clone(),
-                creator_name: self.creator_name.clone(),
-                creator_slug: self.creator_slug.clone(),
-                info_url: UncheckedUrl::of(format!("{}/p/{}", domain, self.creator_slug)),
+                creator_name: creator.creator_name.clone(),
+                creator_slug: creator.creator_slug.clone(),
+                info_url: UncheckedUrl::of(format!("{}/p/{}", domain, creator.creator_slug)),
                 created: created.clone(),
                 transactions: vec![transaction],
                 donated,
@@ -201,17 +439,33 @@ mod collection {
                     domain.to_string(),
                     donated,
                     created,
-                    self.creator_slug.to_string(),
+                    creator.creator_slug,
+                    level.clone(),
                 )),
+                expires_at,
+                level,
             };
 
-            self.membership_resource_manager
-                .mint_ruid_non_fungible(data.clone())
+            let membership = self
+                .membership_resource_manager
+                .mint_ruid_non_fungible(data.clone());
+
+            Runtime::emit_event(MembershipMintedEvent {
+                creator: self.creator_badge_global_id.clone(),
+                membership_id: membership.as_non_fungible().non_fungible_local_id(),
+                donated,
+            });
+
+            membership
         }
 
         // update_membership_metadata is a private method that updates the membership metadata based
         // on the amount donated.
-        fn update_membership_metadata(&mut self, nft_id: NonFungibleLocalId, amount: Decimal) {
+        fn update_membership_metadata(
+            &mut self,
+            nft_id: NonFungibleLocalId,
+            transaction: Transaction,
+        ) {
             // Get the domain name used from the trophy resource manager.
             let domain: String = self
                 .trophy_resource_manager
@@ -224,30 +478,33 @@ mod collection {
                 .membership_resource_manager
                 .get_non_fungible_data(&nft_id);
 
-            assert_eq!(
-                data.creator_slug, self.creator_slug,
-                "The given membership does not match this component."
-            );
-
             assert!(
-                data.creator.eq(&self.creator_badge_global_id),
+                self.is_creator(&data.creator),
                 "The given membership does not match this component."
             );
 
-            let created = generate_created_string(
-                UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap(),
-            );
-
-            let transaction = Transaction { amount, created };
+            if let Some(subscription) = self
+                .repository
+                .get_subscription(self.creator_badge_global_id.clone())
+            {
+                data.expires_at = extend_expiry(data.expires_at, &subscription, &transaction);
+            }
 
             // Generate new data based on the updated donation value.
+            data.donated += self.counted_amount(&transaction);
             data.transactions.push(transaction);
-            data.donated += amount;
+            data.level = resolve_tier(
+                &self
+                    .repository
+                    .get_membership_levels(self.creator_badge_global_id.clone()),
+                data.donated,
+            );
             data.key_image_url = UncheckedUrl::of(generate_membership_url(
                 domain.to_string(),
                 data.donated,
                 data.created,
-                self.creator_slug.clone(),
+                self.get_creator().creator_slug,
+                data.level.clone(),
             ));
 
             // Update NF with new data
@@ -266,33 +523,446 @@ mod collection {
                 "key_image_url",
                 data.key_image_url,
             );
+            self.membership_resource_manager.update_non_fungible_data(
+                &nft_id,
+                "expires_at",
+                data.expires_at,
+            );
+            self.membership_resource_manager
+                .update_non_fungible_data(&nft_id, "level", data.level);
+
+            Runtime::emit_event(MembershipUpdatedEvent {
+                creator: self.creator_badge_global_id.clone(),
+                membership_id: nft_id,
+                donated: data.donated,
+            });
+        }
+
+        // deposit_donation is a private method that takes the platform fee from the donation, and
+        // deposits the remaining tokens into the donations vault. The donation is also counted
+        // towards the funding goal. Returns the fee taken from the donation.
+        fn deposit_donation(&mut self, mut tokens: Bucket, matched: Bucket) -> Decimal {
+            tokens.put(matched);
+
+            if self.is_raised_resource(&tokens.resource_address()) {
+                self.raised += tokens.amount();
+            }
+
+            if let Some(funding_goal) = &self.funding_goal {
+                let before_deadline = funding_goal.deadline.is_none_or(|deadline| {
+                    Clock::current_time_is_at_or_before(deadline, TimePrecision::Minute)
+                });
+
+                if before_deadline && self.raised >= funding_goal.amount {
+                    self.goal_reached = true;
+                }
+            }
+
+            let resource_address = tokens.resource_address();
+            let escrowed = self.is_all_or_nothing();
+
+            // Fees of all-or-nothing campaigns are taken when the campaign is settled, so that
+            // backers can be refunded in full if the funding goal is not reached.
+            let mut fee = dec!(0);
+            if !escrowed {
+                let fee_rate = self.get_fee_rate();
+                let fees = tokens.take_advanced(
+                    tokens.amount() * fee_rate,
+                    WithdrawStrategy::Rounded(RoundingMode::ToZero),
+                );
+                fee = fees.amount();
+                self.fees.get_mut(&resource_address).unwrap().put(fees);
+            }
+
+            // Donations to an all-or-nothing campaign stay in escrow, and are withdrawn when the
+            // campaign has succeeded.
+            if let (Some(account), false) = (self.payout_account.as_mut(), escrowed) {
+                let amount = tokens.amount();
+
+                // The donation is kept in the collection if the account rejects the deposit.
+                match account.try_deposit_or_refund(tokens, None) {
+                    Some(rejected) => {
+                        self.donations
+                            .get_mut(&resource_address)
+                            .unwrap()
+                            .put(rejected);
+                    }
+                    None => {
+                        Runtime::emit_event(DonationPaidOutEvent {
+                            collection_id: self.collection_id.clone(),
+                            account: account.address(),
+                            resource_address,
+                            amount,
+                        });
+                    }
+                }
+
+                return fee;
+            }
+
+            self.donations
+                .get_mut(&resource_address)
+                .unwrap()
+                .put(tokens);
+
+            fee
+        }
+
+        // is_raised_resource is a private method that returns true if donations of the resource
+        // count towards the amount raised. Only the counted resource of the collection is counted.
+        fn is_raised_resource(&self, resource_address: &ResourceAddress) -> bool {
+            self.counted_resource == *resource_address
+        }
+
+        // counted_amount is a private method that returns the amount of a donation that counts
+        // towards the donated amount of trophies and memberships, their tiers, the thanks tokens
+        // and the funded amount of the creator. Only donations in the counted resource are
+        // counted, donations in other resources are kept as transactions only.
+        fn counted_amount(&self, transaction: &Transaction) -> Decimal {
+            counted_amount(transaction, self.counted_resource)
+        }
+
+        // new_transaction is a private method that creates the transaction record of a donation.
+        fn new_transaction(
+            &self,
+            tokens: &Bucket,
+            matched: &Bucket,
+            message: Option<String>,
+        ) -> Transaction {
+            if let Some(message) = &message {
+                validate_message(message);
+            }
+
+            Transaction {
+                amount: tokens.amount(),
+                resource_address: tokens.resource_address(),
+                created: generate_created_string(
+                    UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap(),
+                ),
+                message,
+                matched: matched.amount(),
+                gifter: None,
+            }
+        }
+
+        // get_matched is a private method that returns the donations of a resource that has been
+        // matched from the matching pool.
+        fn get_matched(&self, resource_address: &ResourceAddress) -> Decimal {
+            self.matched
+                .get(resource_address)
+                .map_or(dec!(0), |matched| *matched)
+        }
+
+        // get_sponsored is a private method that returns the funds of a resource given to the
+        // matching pool, that has not been reclaimed.
+        fn get_sponsored(&self, resource_address: &ResourceAddress) -> Decimal {
+            self.sponsored
+                .get(resource_address)
+                .map_or(dec!(0), |sponsored| *sponsored)
+        }
+
+        // take_match is a private method that takes the match of a donation from the matching
+        // pool. The match is limited by the cap of the matching, and by the funds left in the pool.
+        fn take_match(&mut self, tokens: &Bucket) -> Bucket {
+            let resource_address = tokens.resource_address();
+
+            let matching = match &self.matching {
+                Some(matching) => matching.clone(),
+                None => return Bucket::new(resource_address),
+            };
+
+            let matched = self.get_matched(&resource_address);
+            let amount = (tokens.amount() * matching.ratio)
+                .min(matching.cap - matched)
+                .max(dec!(0));
+
+            let mut pool = match self.matching_pool.get_mut(&resource_address) {
+                Some(pool) => pool,
+                None => return Bucket::new(resource_address),
+            };
+
+            let amount = amount.min(pool.amount());
+            let bucket =
+                pool.take_advanced(amount, WithdrawStrategy::Rounded(RoundingMode::ToZero));
+
+            self.matched
+                .insert(resource_address, matched + bucket.amount());
+
+            bucket
+        }
+
+        // emit_donation_event is a private method that emits the event of a donation, carrying the
+        // fee taken, the trophy and membership that received the donation, and the message of the
+        // backer. Renewals of a membership has no trophy.
+        fn emit_donation_event(
+            &self,
+            transaction: Transaction,
+            fee: Decimal,
+            trophy_id: Option<NonFungibleLocalId>,
+            membership_id: NonFungibleLocalId,
+        ) {
+            Runtime::emit_event(DonationEvent {
+                collection_id: self.collection_id.clone(),
+                resource_address: transaction.resource_address,
+                amount: transaction.amount,
+                matched: transaction.matched,
+                fee,
+                trophy_id,
+                membership_id,
+                message: transaction.message,
+                gifter: transaction.gifter,
+            });
+        }
+
+        // assert_accepting_donations is a private method that panics if the collection does not
+        // accept the given donation.
+        fn assert_accepting_donations(&self, tokens: &Bucket) {
+            if self.closed.is_some() {
+                panic!("This collection is permanently closed.");
+            }
+
+            if self.paused.is_some() {
+                panic!("This collection is paused.");
+            }
+
+            if self.repository.get_closed().is_some() {
+                panic!("The repository of this collection is permanently closed.");
+            }
+
+            if self.repository.get_emergency_paused().is_some() {
+                panic!("The repository of this collection is paused in an emergency.");
+            }
+
+            // A released or reassigned slug leaves the creator without one until a new slug is
+            // set, and trophies and memberships are not minted without it.
+            assert!(
+                !self.get_creator().creator_slug.is_empty(),
+                "The creator of this collection has no creator slug, a new one must be set."
+            );
+
+            assert!(
+                self.accepted_resources.contains(&tokens.resource_address()),
+                "The given resource is not accepted as donation by this collection."
+            );
+
+            let donation_limits = self
+                .get_donation_limits(tokens.resource_address())
+                .expect("The given resource has no donation limits in this collection.");
+
+            assert!(
+                tokens.amount() >= donation_limits.minimum,
+                "The donation must not be lower than the minimum donation of {}.",
+                donation_limits.minimum
+            );
+
+            if let Some(maximum) = donation_limits.maximum {
+                assert!(
+                    tokens.amount() <= maximum,
+                    "The donation must not be higher than the maximum donation of {}.",
+                    maximum
+                );
+            }
+
+            if self.is_all_or_nothing() && self.campaign_ended() {
+                panic!("The campaign of this collection has ended.");
+            }
+        }
+
+        // pause is a private method that pauses the collection, recording whether the pause was
+        // made by the repository owner.
+        fn pause(&mut self, by_repository_owner: bool) {
+            if self.closed.is_some() {
+                panic!("This collection is permanently closed.");
+            }
+
+            assert!(self.paused.is_none(), "This collection is already paused.");
+
+            let paused =
+                UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap();
+
+            self.paused = Some(paused);
+            self.paused_by_repository_owner = by_repository_owner;
+
+            Runtime::emit_event(CollectionPausedEvent {
+                collection_id: self.collection_id.clone(),
+                paused,
+                by_repository_owner,
+            });
+        }
+
+        // is_all_or_nothing is a private method that returns true if donations are held in escrow
+        // until the funding goal is reached.
+        fn is_all_or_nothing(&self) -> bool {
+            self.funding_goal
+                .as_ref()
+                .is_some_and(|funding_goal| funding_goal.all_or_nothing)
+        }
+
+        // campaign_ended is a private method that returns true if the deadline of the funding
+        // goal has passed.
+        fn campaign_ended(&self) -> bool {
+            self.funding_goal
+                .as_ref()
+                .and_then(|funding_goal| funding_goal.deadline)
+                .is_some_and(|deadline| {
+                    Clock::current_time_is_strictly_after(deadline, TimePrecision::Minute)
+                })
+        }
+
+        // is_refundable is a private method that returns true if an all-or-nothing campaign has
+        // failed, either by ending without reaching the funding goal or by being closed before it
+        // was settled.
+        fn is_refundable(&self) -> bool {
+            self.is_all_or_nothing()
+                && !self.settled
+                && (self.closed.is_some() || (self.campaign_ended() && !self.goal_reached))
+        }
+
+        // settle_campaign is a private method that takes the fees of a successful all-or-nothing
+        // campaign, which releases the escrowed donations to the creator.
+        fn settle_campaign(&mut self) {
+            if !self.is_all_or_nothing() || self.settled {
+                return;
+            }
+
+            assert!(
+                self.campaign_ended(),
+                "The campaign of this collection has not ended yet."
+            );
+
+            assert!(
+                self.goal_reached,
+                "The campaign of this collection did not reach its funding goal."
+            );
+
+            let fee_rate = self.get_fee_rate();
+            for resource_address in self.resources.iter() {
+                let mut donations = self.donations.get_mut(resource_address).unwrap();
+                let amount = donations.amount() * fee_rate;
+                let fees = donations
+                    .take_advanced(amount, WithdrawStrategy::Rounded(RoundingMode::ToZero));
+                self.fees.get_mut(resource_address).unwrap().put(fees);
+            }
+            self.settled = true;
+
+            Runtime::emit_event(CampaignSettledEvent {
+                collection_id: self.collection_id.clone(),
+                raised: self.raised,
+            });
+        }
+
+        // get_allocated is a private method that returns the donations of a resource that has been
+        // allocated to payees, but not claimed.
+        fn get_allocated(&self, resource_address: &ResourceAddress) -> Decimal {
+            self.allocated
+                .get(resource_address)
+                .map_or(dec!(0), |allocated| *allocated)
+        }
+
+        // get_unallocated is a private method that returns the donations of a resource that has
+        // not been allocated to payees.
+        fn get_unallocated(&self, resource_address: &ResourceAddress) -> Decimal {
+            self.donations.get(resource_address).unwrap().amount()
+                - self.get_allocated(resource_address)
+        }
+
+        // get_released is a private method that returns the donations of a resource that has been
+        // released to the owner or to payees.
+        fn get_released(&self, resource_address: &ResourceAddress) -> Decimal {
+            self.released
+                .get(resource_address)
+                .map_or(dec!(0), |released| *released)
+        }
+
+        // get_unlocked is a private method that returns the donations of a resource that has not
+        // been allocated to payees, and that the vesting has unlocked. Donations already released
+        // count towards the unlocked share.
+        fn get_unlocked(&self, resource_address: &ResourceAddress) -> Decimal {
+            let unallocated = self.get_unallocated(resource_address);
+
+            match &self.vesting {
+                Some(vesting) => {
+                    let released = self.get_released(resource_address);
+                    let unlocked = (unallocated + released) * unlocked_share(vesting) - released;
+
+                    unlocked.max(dec!(0)).min(unallocated)
+                }
+                None => unallocated,
+            }
+        }
+
+        // get_allocated_balance is a private method that returns the donations allocated to a
+        // payee, that has not been claimed.
+        fn get_allocated_balance(&self, payee: &Payee) -> Vec<(ResourceAddress, Decimal)> {
+            self.split_balances
+                .get(payee)
+                .map(|balances| balances.clone())
+                .unwrap_or_default()
+        }
+
+        // is_payee is a private method that returns true if the payee is one of the payees of the
+        // collection, or has donations allocated to it by a previous split.
+        fn is_payee(&self, payee: &Payee) -> bool {
+            self.splits.iter().any(|split| split.payee == *payee)
+                || self.split_balances.get(payee).is_some()
+        }
+
+        // is_allocatable is a private method that returns true if the donations can be allocated to
+        // payees. Escrowed donations of an all-or-nothing campaign are only allocated once the
+        // campaign has been settled.
+        fn is_allocatable(&self) -> bool {
+            !self.splits.is_empty() && (!self.is_all_or_nothing() || self.settled)
+        }
+
+        // allocate_splits is a private method that allocates the donations that has not been
+        // allocated yet to the payees, according to their shares.
+        fn allocate_splits(&mut self) {
+            if !self.is_allocatable() {
+                return;
+            }
+
+            for resource_address in self.resources.clone() {
+                let unlocked = self.get_unlocked(&resource_address);
+                if unlocked == dec!(0) {
+                    continue;
+                }
+
+                let amounts = split_amounts(&self.splits, unlocked);
+                for (split, amount) in self.splits.iter().zip(amounts) {
+                    let mut balances = self.get_allocated_balance(&split.payee);
+                    add_amount(&mut balances, resource_address, amount);
+                    self.split_balances.insert(split.payee.clone(), balances);
+                }
+
+                let allocated = self.get_allocated(&resource_address) + unlocked;
+                self.allocated.insert(resource_address, allocated);
+
+                let released = self.get_released(&resource_address) + unlocked;
+                self.released.insert(resource_address, released);
+            }
         }
 
         // mint_trophy is a private method that mints a trophy NFT based on the amount donated.
-        fn mint_trophy(&mut self, amount: Decimal) -> Bucket {
+        fn mint_trophy(&mut self, transaction: Transaction) -> Bucket {
             let domain: String = self
                 .trophy_resource_manager
                 .get_metadata("domain")
                 .unwrap()
                 .expect("No domain on NFT repository");
 
-            let created = generate_created_string(
-                UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap(),
-            );
-
-            let transaction = Transaction {
-                amount,
-                created: created.clone(),
-            };
+            let amount = self.counted_amount(&transaction);
+            let created = transaction.created.clone();
+            let tier = resolve_tier(&self.tiers, amount);
+            let creator = self.get_creator();
 
             // Create the trophy data.
             let data = Trophy {
                 name: self.trophy_name.clone(),
                 description: self.trophy_description.clone(),
                 creator: self.creator_badge_global_id.clone(),
-                creator_name: self.creator_name.clone(),
-                creator_slug: self.creator_slug.clone(),
-                info_url: UncheckedUrl::of(format!("{}/p/{}", domain, self.creator_slug)),
+                creator_name: creator.creator_name,
+                creator_slug: creator.creator_slug.clone(),
+                info_url: UncheckedUrl::of(format!("{}/p/{}", domain, creator.creator_slug)),
                 collection_id: self.collection_id.clone(),
                 created: created.clone(),
                 transactions: vec![transaction],
@@ -302,17 +972,29 @@ mod collection {
                     amount,
                     created.clone(),
                     self.collection_id.clone(),
+                    tier.clone(),
                 )),
+                refunded: false,
+                tier,
             };
 
             // Mint the trophy NFT.
-            self.trophy_resource_manager
-                .mint_ruid_non_fungible(data.clone())
+            let trophy = self
+                .trophy_resource_manager
+                .mint_ruid_non_fungible(data.clone());
+
+            Runtime::emit_event(TrophyMintedEvent {
+                collection_id: self.collection_id.clone(),
+                trophy_id: trophy.as_non_fungible().non_fungible_local_id(),
+                donated: amount,
+            });
+
+            trophy
         }
 
         // update_trophy_metadata is a private method that updates the trophy metadata based on the
         // amount donated.
-        fn update_trophy_metadata(&mut self, nft_id: NonFungibleLocalId, amount: Decimal) {
+        fn update_trophy_metadata(&mut self, nft_id: NonFungibleLocalId, transaction: Transaction) {
             // Get the domain name used from the trophy resource manager.
             let domain: String = self
                 .trophy_resource_manager
@@ -330,24 +1012,22 @@ mod collection {
             );
 
             assert!(
-                data.creator.eq(&self.creator_badge_global_id),
+                self.is_creator(&data.creator),
                 "The given membership does not match this component."
             );
 
-            let created = generate_created_string(
-                UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap(),
-            );
-
-            let transaction = Transaction { amount, created };
+            assert!(!data.refunded, "The given trophy has been refunded.");
 
             // Generate new data based on the updated donation value.
+            data.donated += self.counted_amount(&transaction);
             data.transactions.push(transaction);
-            data.donated += amount;
+            data.tier = resolve_tier(&self.tiers, data.donated);
             data.key_image_url = UncheckedUrl::of(generate_trophy_url(
                 domain.to_string(),
                 data.donated,
                 data.created,
                 self.collection_id.clone(),
+                data.tier.clone(),
             ));
 
             // Update NF with new data
@@ -358,116 +1038,183 @@ mod collection {
             );
             self.trophy_resource_manager
                 .update_non_fungible_data(&nft_id, "donated", data.donated);
+            self.trophy_resource_manager
+                .update_non_fungible_data(&nft_id, "tier", data.tier);
             self.trophy_resource_manager.update_non_fungible_data(
                 &nft_id,
                 "key_image_url",
                 data.key_image_url,
             );
+
+            Runtime::emit_event(TrophyUpdatedEvent {
+                collection_id: self.collection_id.clone(),
+                trophy_id: nft_id,
+                donated: data.donated,
+            });
         }
 
         // donate_mint is a public method, callable by anyone who want to donate to the user. In
         // return they will get a trophy NFT that represents the donation.
-        pub fn donate_mint(&mut self, mut tokens: Bucket) -> (Bucket, Bucket, Bucket) {
-            if self.closed.is_some() {
-                panic!("This collection is permanently closed.");
-            }
+        pub fn donate_mint(
+            &mut self,
+            tokens: Bucket,
+            message: Option<String>,
+        ) -> (Bucket, Bucket, Bucket) {
+            self.assert_accepting_donations(&tokens);
+
+            let matched = self.take_match(&tokens);
+            let transaction = self.new_transaction(&tokens, &matched, message);
 
             // Push a proof of minter badge to the local auth zone for minting a trophy.
             LocalAuthZone::push(self.minter_badge.as_fungible().create_proof_of_amount(1));
 
-            // Update creator badge
-            self.update_creator_metadata(tokens.amount());
+            // Update creator badge with the counted amount of the donation.
+            let amount = self.counted_amount(&transaction);
+            self.update_creator_metadata(amount);
 
-            let trophy = self.mint_trophy(tokens.amount());
+            let trophy = self.mint_trophy(transaction.clone());
+            let trophy_id = trophy.as_non_fungible().non_fungible_local_id();
 
-            let membership = self.mint_membership(tokens.amount());
+            let membership = self.mint_membership(transaction.clone());
+            let membership_id = membership.as_non_fungible().non_fungible_local_id();
 
-            let thanks = self.thanks_token_resource_manager.mint(tokens.amount());
+            let thanks = self.thanks_token_resource_manager.mint(amount);
 
-            self.fees.put(tokens.take(tokens.amount() * dec!(0.04)));
-
-            self.donations.put(tokens);
+            let fee = self.deposit_donation(tokens, matched);
+            self.emit_donation_event(transaction, fee, Some(trophy_id), membership_id);
 
             (trophy, thanks, membership)
         }
 
+        // donate_gift is a public method, callable by anyone who want to donate to the user in the
+        // name of someone else. The trophy and the membership are deposited into the account of
+        // the recipient, and the gifter receives the thanks tokens. The gifter is recorded by a
+        // badge, such as the signature of the gifter, that must be present.
+        pub fn donate_gift(
+            &mut self,
+            tokens: Bucket,
+            gifter: NonFungibleGlobalId,
+            recipient: ComponentAddress,
+            message: Option<String>,
+        ) -> Bucket {
+            Runtime::assert_access_rule(rule!(require(gifter.clone())));
+
+            self.assert_accepting_donations(&tokens);
+
+            let matched = self.take_match(&tokens);
+            let mut transaction = self.new_transaction(&tokens, &matched, message);
+            transaction.gifter = Some(gifter.clone());
+
+            // Push a proof of minter badge to the local auth zone for minting a trophy.
+            LocalAuthZone::push(self.minter_badge.as_fungible().create_proof_of_amount(1));
+
+            // Update creator badge with the counted amount of the donation.
+            let amount = self.counted_amount(&transaction);
+            self.update_creator_metadata(amount);
+
+            let trophy = self.mint_trophy(transaction.clone());
+            let trophy_id = trophy.as_non_fungible().non_fungible_local_id();
+
+            let membership = self.mint_membership(transaction.clone());
+            let membership_id = membership.as_non_fungible().non_fungible_local_id();
+
+            let thanks = self.thanks_token_resource_manager.mint(amount);
+
+            // The gift is aborted if the account of the recipient does not accept the deposit.
+            Global::<Account>::from(recipient)
+                .try_deposit_batch_or_abort(vec![trophy, membership], None);
+
+            Runtime::emit_event(GiftEvent {
+                collection_id: self.collection_id.clone(),
+                trophy_id: trophy_id.clone(),
+                membership_id: membership_id.clone(),
+                gifter,
+                recipient,
+            });
+
+            let fee = self.deposit_donation(tokens, matched);
+            self.emit_donation_event(transaction, fee, Some(trophy_id), membership_id);
+
+            thanks
+        }
+
         // donate_mint_with_membership is a public method, callable by anyone who want to donate to
         // the user. In return they will get a trophy NFT that represents the donation. This method
         // requires a membership proof to be passed in.
         pub fn donate_mint_with_membership(
             &mut self,
-            mut tokens: Bucket,
+            tokens: Bucket,
             membership_proof: Proof,
+            message: Option<String>,
         ) -> (Bucket, Bucket) {
-            if self.closed.is_some() {
-                panic!("This collection is permanently closed.");
-            }
+            self.assert_accepting_donations(&tokens);
+
+            let matched = self.take_match(&tokens);
+            let transaction = self.new_transaction(&tokens, &matched, message);
 
             // Push a proof of minter badge to the local auth zone for minting a trophy.
             LocalAuthZone::push(self.minter_badge.as_fungible().create_proof_of_amount(1));
 
-            // Update creator badge
-            self.update_creator_metadata(tokens.amount());
+            // Update creator badge with the counted amount of the donation.
+            let amount = self.counted_amount(&transaction);
+            self.update_creator_metadata(amount);
 
             let checked_membership_proof =
                 membership_proof.check(self.membership_resource_manager.address());
+            let membership_id = checked_membership_proof
+                .as_non_fungible()
+                .non_fungible_local_id();
 
             // Update membership badge
-            self.update_membership_metadata(
-                checked_membership_proof
-                    .as_non_fungible()
-                    .non_fungible_local_id(),
-                tokens.amount(),
-            );
+            self.update_membership_metadata(membership_id.clone(), transaction.clone());
 
-            let trophy = self.mint_trophy(tokens.amount());
+            let trophy = self.mint_trophy(transaction.clone());
+            let trophy_id = trophy.as_non_fungible().non_fungible_local_id();
 
             // Mint thanks tokens equal to the donated amount.
-            let thanks = self.thanks_token_resource_manager.mint(tokens.amount());
-
-            // Take fees from the donation.
-            self.fees.put(tokens.take(tokens.amount() * dec!(0.04)));
+            let thanks = self.thanks_token_resource_manager.mint(amount);
 
-            // Take all tokens, and return trophy.
-            self.donations.put(tokens);
+            // Take fees from the donation, and deposit the rest.
+            let fee = self.deposit_donation(tokens, matched);
+            self.emit_donation_event(transaction, fee, Some(trophy_id), membership_id);
             (trophy, thanks)
         }
 
         // donate_update is a public method, callable by anyone who want to donate to the user.
         pub fn donate_update(
             &mut self,
-            mut tokens: Bucket,
+            tokens: Bucket,
             trophy_proof: Proof,
+            message: Option<String>,
         ) -> (Bucket, Bucket) {
-            if self.closed.is_some() {
-                panic!("This collection is permanently closed.");
-            }
+            self.assert_accepting_donations(&tokens);
+
+            let matched = self.take_match(&tokens);
+            let transaction = self.new_transaction(&tokens, &matched, message);
 
             // Push a proof of minter badge to the local auth zone for minting a trophy.
             LocalAuthZone::push(self.minter_badge.as_fungible().create_proof_of_amount(1));
 
-            // Update creator badge
-            self.update_creator_metadata(tokens.amount());
+            // Update creator badge with the counted amount of the donation.
+            let amount = self.counted_amount(&transaction);
+            self.update_creator_metadata(amount);
 
             // Check that the proof is of same resource address.
             let checked_proof = trophy_proof.check(self.trophy_resource_manager.address());
+            let trophy_id = checked_proof.as_non_fungible().non_fungible_local_id();
 
             // Update trophy NF metadata
-            self.update_trophy_metadata(
-                checked_proof.as_non_fungible().non_fungible_local_id(),
-                tokens.amount(),
-            );
+            self.update_trophy_metadata(trophy_id.clone(), transaction.clone());
 
-            let membership = self.mint_membership(tokens.amount());
+            let membership = self.mint_membership(transaction.clone());
+            let membership_id = membership.as_non_fungible().non_fungible_local_id();
 
             // Mint thanks tokens equal to the donated amount.
-            let thanks = self.thanks_token_resource_manager.mint(tokens.amount());
-
-            // Take fees from the donation.
-            self.fees.put(tokens.take(tokens.amount() * dec!(0.04)));
+            let thanks = self.thanks_token_resource_manager.mint(amount);
 
-            // Take all tokens, and return trophy.
-            self.donations.put(tokens);
+            // Take fees from the donation, and deposit the rest.
+            let fee = self.deposit_donation(tokens, matched);
+            self.emit_donation_event(transaction, fee, Some(trophy_id), membership_id);
             (thanks, membership)
         }
 
@@ -475,75 +1222,933 @@ mod collection {
         // This method requires a membership proof, and trophy proof to be passed in.
         pub fn donate_update_with_membership(
             &mut self,
-            mut tokens: Bucket,
+            tokens: Bucket,
             trophy_proof: Proof,
             membership_proof: Proof,
+            message: Option<String>,
         ) -> Bucket {
-            if self.closed.is_some() {
-                panic!("This collection is permanently closed.");
-            }
+            self.assert_accepting_donations(&tokens);
+
+            let matched = self.take_match(&tokens);
+            let transaction = self.new_transaction(&tokens, &matched, message);
 
             // Push a proof of minter badge to the local auth zone for minting a trophy.
             LocalAuthZone::push(self.minter_badge.as_fungible().create_proof_of_amount(1));
 
-            // Update creator badge
-            self.update_creator_metadata(tokens.amount());
+            // Update creator badge with the counted amount of the donation.
+            let amount = self.counted_amount(&transaction);
+            self.update_creator_metadata(amount);
 
             let checked_membership_proof =
                 membership_proof.check(self.membership_resource_manager.address());
+            let membership_id = checked_membership_proof
+                .as_non_fungible()
+                .non_fungible_local_id();
 
             // Update membership badge
-            self.update_membership_metadata(
-                checked_membership_proof
-                    .as_non_fungible()
-                    .non_fungible_local_id(),
-                tokens.amount(),
-            );
+            self.update_membership_metadata(membership_id.clone(), transaction.clone());
 
             // Check that the proof is of same resource address.
             let checked_trophy_proof = trophy_proof.check(self.trophy_resource_manager.address());
+            let trophy_id = checked_trophy_proof
+                .as_non_fungible()
+                .non_fungible_local_id();
 
-            self.update_trophy_metadata(
-                checked_trophy_proof
-                    .as_non_fungible()
-                    .non_fungible_local_id(),
-                tokens.amount(),
-            );
+            self.update_trophy_metadata(trophy_id.clone(), transaction.clone());
 
             // Mint thanks tokens equal to the donated amount.
-            let thanks = self.thanks_token_resource_manager.mint(tokens.amount());
+            let thanks = self.thanks_token_resource_manager.mint(amount);
 
-            // Take fees from the donation.
-            self.fees.put(tokens.take(tokens.amount() * dec!(0.04)));
-
-            // Take all tokens, and return trophy.
-            self.donations.put(tokens);
+            // Take fees from the donation, and deposit the rest.
+            let fee = self.deposit_donation(tokens, matched);
+            self.emit_donation_event(transaction, fee, Some(trophy_id), membership_id);
             thanks
         }
 
-        // withdraw_donations is a method for the admin to withdraw all donations.
-        pub fn withdraw_donations(&mut self) -> Bucket {
-            self.donations.take_all()
-        }
+        // renew_membership is a public method, callable by backers who want to extend the
+        // membership subscription of the creator without receiving a trophy. The donation must be
+        // at least the subscription price.
+        pub fn renew_membership(
+            &mut self,
+            tokens: Bucket,
+            membership_proof: Proof,
+            message: Option<String>,
+        ) -> Bucket {
+            self.assert_accepting_donations(&tokens);
 
-        // withdraw_fees is a method for the repository owner to withdraw all fees.
-        pub fn withdraw_fees(&mut self) -> Bucket {
-            self.fees.take_all()
-        }
+            let subscription = self
+                .repository
+                .get_subscription(self.creator_badge_global_id.clone())
+                .expect("The creator of this collection does not offer membership subscriptions.");
+
+            assert_eq!(
+                tokens.resource_address(),
+                subscription.resource_address,
+                "The given resource is not accepted for membership subscriptions."
+            );
+
+            assert!(
+                tokens.amount() >= subscription.price,
+                "The donation must not be lower than the subscription price of {}.",
+                subscription.price
+            );
+
+            let matched = self.take_match(&tokens);
+            let transaction = self.new_transaction(&tokens, &matched, message);
+
+            // Push a proof of minter badge to the local auth zone for updating the membership.
+            LocalAuthZone::push(self.minter_badge.as_fungible().create_proof_of_amount(1));
+
+            // Update creator badge with the counted amount of the donation.
+            let amount = self.counted_amount(&transaction);
+            self.update_creator_metadata(amount);
+
+            let checked_membership_proof =
+                membership_proof.check(self.membership_resource_manager.address());
+            let membership_id = checked_membership_proof
+                .as_non_fungible()
+                .non_fungible_local_id();
+
+            // Update membership badge, which extends the expiry.
+            self.update_membership_metadata(membership_id.clone(), transaction.clone());
+
+            let data: Membership = self
+                .membership_resource_manager
+                .get_non_fungible_data(&membership_id);
+
+            // Mint thanks tokens equal to the donated amount.
+            let thanks = self.thanks_token_resource_manager.mint(amount);
+
+            // Take fees from the donation, and deposit the rest.
+            let fee = self.deposit_donation(tokens, matched);
+            self.emit_donation_event(transaction.clone(), fee, None, membership_id.clone());
+
+            Runtime::emit_event(MembershipRenewedEvent {
+                creator: self.creator_badge_global_id.clone(),
+                membership_id,
+                amount: transaction.amount,
+                expires_at: data.expires_at,
+            });
+
+            thanks
+        }
+
+        // withdraw_donations is a method for the admin to withdraw all donations. Donations to an
+        // all-or-nothing campaign can only be withdrawn after the campaign has succeeded, and only
+        // the unlocked donations are withdrawn when vesting is set. Donations split between payees
+        // are claimed by each payee instead.
+        pub fn withdraw_donations(&mut self) -> Vec<Bucket> {
+            assert!(
+                self.splits.is_empty(),
+                "The donations of this collection are split between payees, and are claimed by each payee."
+            );
+
+            self.settle_campaign();
+
+            let mut withdrawals: Vec<Bucket> = vec![];
+            for resource_address in self.resources.clone() {
+                // Donations allocated to payees of a previous split are kept for them to claim.
+                let amount = self.get_unlocked(&resource_address);
+                let donations = self
+                    .donations
+                    .get_mut(&resource_address)
+                    .unwrap()
+                    .take_advanced(amount, WithdrawStrategy::Rounded(RoundingMode::ToZero));
+
+                let released = self.get_released(&resource_address) + donations.amount();
+                self.released.insert(resource_address, released);
+
+                Runtime::emit_event(WithdrawalEvent {
+                    collection_id: self.collection_id.clone(),
+                    resource_address,
+                    amount: donations.amount(),
+                });
+
+                withdrawals.push(donations);
+            }
+
+            withdrawals
+        }
+
+        // set_matching is a method for the collection owner to match the donations from the
+        // matching pool, by a ratio of the donated amount up to a cap of matched donations per
+        // resource. None stops matching the donations. The terms are fixed once sponsors has
+        // funded the pool, and can only be changed or stopped while it is empty.
+        pub fn set_matching(&mut self, matching: Option<Matching>) {
+            if let Some(matching) = &matching {
+                validate_matching(matching);
+            }
+
+            assert!(
+                self.resources
+                    .iter()
+                    .all(|resource_address| self.get_matching_pool(*resource_address) == dec!(0)),
+                "The matching can not be changed while the matching pool holds funds."
+            );
+
+            self.matching = matching.clone();
+
+            Runtime::emit_event(MatchingChangedEvent {
+                collection_id: self.collection_id.clone(),
+                matching,
+            });
+        }
+
+        // get_matching returns the matching of the donations, or None if donations are not matched.
+        pub fn get_matching(&self) -> Option<Matching> {
+            self.matching.clone()
+        }
+
+        // fund_matching_pool is a public method for sponsors to fund the matching pool, that
+        // matches the donations to the collection on the current terms. The sponsor is recorded
+        // by a badge, such as the signature of the sponsor, that must be present. What is left in
+        // the pool when the collection is closed can be reclaimed by the sponsor.
+        pub fn fund_matching_pool(&mut self, tokens: Bucket, sponsor: NonFungibleGlobalId) {
+            Runtime::assert_access_rule(rule!(require(sponsor.clone())));
+
+            if self.closed.is_some() {
+                panic!("This collection is permanently closed.");
+            }
+
+            assert!(
+                self.matching.is_some(),
+                "This collection does not match donations."
+            );
+
+            let resource_address = tokens.resource_address();
+            assert!(
+                self.accepted_resources.contains(&resource_address),
+                "The given resource is not accepted as donation by this collection."
+            );
+
+            let amount = tokens.amount();
+
+            let mut contributions = self
+                .sponsors
+                .get(&sponsor)
+                .map(|contributions| contributions.clone())
+                .unwrap_or_default();
+            add_amount(&mut contributions, resource_address, amount);
+            self.sponsors.insert(sponsor.clone(), contributions);
+
+            let sponsored = self.get_sponsored(&resource_address) + amount;
+            self.sponsored.insert(resource_address, sponsored);
+
+            if self.matching_pool.get(&resource_address).is_none() {
+                self.matching_pool
+                    .insert(resource_address, Vault::new(resource_address));
+            }
+
+            self.matching_pool
+                .get_mut(&resource_address)
+                .unwrap()
+                .put(tokens);
+
+            Runtime::emit_event(MatchingPoolFundedEvent {
+                collection_id: self.collection_id.clone(),
+                sponsor,
+                resource_address,
+                amount,
+            });
+        }
+
+        // reclaim_matching_pool is a public method for a sponsor to reclaim its share in what is
+        // left of the matching pool, once the collection is closed or its all-or-nothing campaign
+        // has failed. The sponsor badge must be present.
+        pub fn reclaim_matching_pool(&mut self, sponsor: NonFungibleGlobalId) -> Vec<Bucket> {
+            Runtime::assert_access_rule(rule!(require(sponsor.clone())));
+
+            assert!(
+                self.closed.is_some() || self.is_refundable(),
+                "The matching pool can only be reclaimed when the collection is closed."
+            );
+
+            let contributions = self
+                .sponsors
+                .remove(&sponsor)
+                .expect("The given sponsor has not funded the matching pool.");
+
+            let mut reclaimed: Vec<Bucket> = vec![];
+            for (resource_address, contribution) in contributions {
+                // Sponsors share what is left of the pool in proportion to what they gave.
+                let sponsored = self.get_sponsored(&resource_address);
+                let share = self.get_matching_pool(resource_address) * contribution / sponsored;
+                let funds = self
+                    .matching_pool
+                    .get_mut(&resource_address)
+                    .unwrap()
+                    .take_advanced(share, WithdrawStrategy::Rounded(RoundingMode::ToZero));
+
+                self.sponsored
+                    .insert(resource_address, sponsored - contribution);
+
+                Runtime::emit_event(MatchingPoolReclaimedEvent {
+                    collection_id: self.collection_id.clone(),
+                    sponsor: sponsor.clone(),
+                    resource_address,
+                    amount: funds.amount(),
+                });
+
+                reclaimed.push(funds);
+            }
+
+            reclaimed
+        }
+
+        // get_matching_pool returns the funds left in the matching pool for a resource.
+        pub fn get_matching_pool(&self, resource_address: ResourceAddress) -> Decimal {
+            self.matching_pool
+                .get(&resource_address)
+                .map_or(dec!(0), |pool| pool.amount())
+        }
+
+        // set_vesting is a method for the collection owner to release the donations progressively,
+        // linearly over a period or per milestone approved by the repository admin. Vesting can
+        // only be set once, and applies to the donations that has not been withdrawn yet.
+        pub fn set_vesting(&mut self, vesting: Vesting) {
+            assert!(
+                self.vesting.is_none(),
+                "Vesting has already been set for this collection."
+            );
+
+            assert!(
+                self.payout_account.is_none(),
+                "The donations of this collection are paid out to an account, and can not be vested."
+            );
+
+            validate_vesting(&vesting);
+
+            self.vesting = Some(vesting.clone());
+
+            Runtime::emit_event(VestingSetEvent {
+                collection_id: self.collection_id.clone(),
+                vesting,
+            });
+        }
+
+        // get_vesting returns the vesting of the collection, or None if it is not set.
+        pub fn get_vesting(&self) -> Option<Vesting> {
+            self.vesting.clone()
+        }
+
+        // approve_milestone is a method for the repository to approve a milestone of the vesting,
+        // unlocking its share of the donations.
+        pub fn approve_milestone(&mut self, index: u32) {
+            let milestones = match &mut self.vesting {
+                Some(Vesting::Milestones(milestones)) => milestones,
+                _ => panic!("This collection is not vested by milestones."),
+            };
+
+            let milestone = milestones
+                .get_mut(index as usize)
+                .expect("Milestone does not exist.");
+
+            assert!(!milestone.approved, "Milestone is already approved.");
+
+            milestone.approved = true;
+
+            Runtime::emit_event(MilestoneApprovedEvent {
+                collection_id: self.collection_id.clone(),
+                index,
+                description: milestone.description.clone(),
+            });
+        }
+
+        // get_vesting_balance returns the locked and the unlocked donations of a resource, that has
+        // not been withdrawn or allocated to payees.
+        pub fn get_vesting_balance(&self, resource_address: ResourceAddress) -> (Decimal, Decimal) {
+            assert!(
+                self.resources.contains(&resource_address),
+                "Resource has not been donated to this collection."
+            );
+
+            let unlocked = self.get_unlocked(&resource_address);
+            let locked = self.get_unallocated(&resource_address) - unlocked;
+
+            (locked, unlocked)
+        }
+
+        // set_auto_payout is a method for the collection owner to have the donations, after fees,
+        // deposited directly into an account when they are made. None turns auto-payout off.
+        pub fn set_auto_payout(&mut self, account: Option<ComponentAddress>) {
+            assert!(
+                account.is_none() || self.splits.is_empty(),
+                "The donations of this collection are split between payees, and can not be paid out to an account."
+            );
+
+            assert!(
+                account.is_none() || self.vesting.is_none(),
+                "The donations of this collection are vested, and can not be paid out to an account."
+            );
+
+            self.payout_account = account.map(Global::<Account>::from);
+
+            Runtime::emit_event(AutoPayoutChangedEvent {
+                collection_id: self.collection_id.clone(),
+                account,
+            });
+        }
+
+        // get_auto_payout returns the account that receives the donations directly, or None if
+        // auto-payout is off.
+        pub fn get_auto_payout(&self) -> Option<ComponentAddress> {
+            self.payout_account
+                .as_ref()
+                .map(|account| account.address())
+        }
+
+        // set_splits is a method for the collection owner to split the donations between payees,
+        // given as badges or accounts with shares in basis points. Donations received before the
+        // change are allocated to the previous payees. No splits makes the owner withdraw the
+        // donations.
+        pub fn set_splits(&mut self, splits: Vec<Split>) {
+            validate_splits(&splits);
+            assert!(
+                splits.is_empty() || self.payout_account.is_none(),
+                "The donations of this collection are paid out to an account, and can not be split between payees."
+            );
+
+            self.allocate_splits();
+            self.splits = splits.clone();
+
+            Runtime::emit_event(SplitsChangedEvent {
+                collection_id: self.collection_id.clone(),
+                splits,
+            });
+        }
+
+        // get_splits returns the payees sharing the donations of the collection.
+        pub fn get_splits(&self) -> Vec<Split> {
+            self.splits.clone()
+        }
+
+        // get_split_balance returns the donations that the payee can claim, per resource.
+        pub fn get_split_balance(&self, payee: Payee) -> Vec<(ResourceAddress, Decimal)> {
+            let mut balances = self.get_allocated_balance(&payee);
+
+            // Include the share of the donations that will be allocated on the next claim.
+            let index = self.splits.iter().position(|split| split.payee == payee);
+            if let (Some(index), true) = (index, self.is_allocatable()) {
+                for resource_address in self.resources.iter() {
+                    let amounts = split_amounts(&self.splits, self.get_unlocked(resource_address));
+                    add_amount(&mut balances, *resource_address, amounts[index]);
+                }
+            }
+
+            balances
+        }
+
+        // claim_share is a public method for a payee to claim the share of the donations allocated
+        // to them. The badge of a badge payee must be present, while the share of an account payee
+        // is deposited into the account, by anyone.
+        pub fn claim_share(&mut self, payee: Payee) -> Vec<Bucket> {
+            assert!(
+                self.is_payee(&payee),
+                "The given payee is not a payee of this collection."
+            );
+
+            if let Payee::Badge(badge) = &payee {
+                Runtime::assert_access_rule(rule!(require(badge.clone())));
+            }
+
+            self.settle_campaign();
+            self.allocate_splits();
+
+            let mut remaining: Vec<(ResourceAddress, Decimal)> = vec![];
+            let mut shares: Vec<Bucket> = vec![];
+            for (resource_address, balance) in self.get_allocated_balance(&payee) {
+                let share = self
+                    .donations
+                    .get_mut(&resource_address)
+                    .unwrap()
+                    .take_advanced(balance, WithdrawStrategy::Rounded(RoundingMode::ToZero));
+                let amount = share.amount();
+
+                // Amounts below the divisibility of the resource remain allocated to the payee.
+                remaining.push((resource_address, balance - amount));
+                let allocated = self.get_allocated(&resource_address) - amount;
+                self.allocated.insert(resource_address, allocated);
+
+                Runtime::emit_event(ShareClaimedEvent {
+                    collection_id: self.collection_id.clone(),
+                    payee: payee.clone(),
+                    resource_address,
+                    amount,
+                });
+
+                shares.push(share);
+            }
+            self.split_balances.insert(payee.clone(), remaining);
+
+            match payee {
+                Payee::Badge(_) => shares,
+                Payee::Account(account) => {
+                    // The claim is aborted if the account of the payee does not accept the deposit.
+                    Global::<Account>::from(account).try_deposit_batch_or_abort(shares, None);
+                    vec![]
+                }
+            }
+        }
+
+        // withdraw_fees is a method for the repository owner to withdraw all fees. Fees of an
+        // all-or-nothing campaign are only taken once it has succeeded, the fee vaults are empty
+        // until then.
+        pub fn withdraw_fees(&mut self) -> Vec<Bucket> {
+            if self.campaign_ended() && self.goal_reached {
+                self.settle_campaign();
+            }
+
+            self.resources
+                .iter()
+                .map(|resource_address| {
+                    let fees = self.fees.get_mut(resource_address).unwrap().take_all();
+
+                    Runtime::emit_event(FeeWithdrawalEvent {
+                        collection_id: self.collection_id.clone(),
+                        resource_address: *resource_address,
+                        amount: fees.amount(),
+                    });
+
+                    fees
+                })
+                .collect()
+        }
 
         // close_collection is a method for the collection admin to close the collection
         // permanently. This will prevent any further donations to be made to the collection, and
         // will prevent any further minting or updating to the trophies.
-        pub fn close_collection(&mut self) -> Bucket {
+        pub fn close_collection(&mut self) -> Vec<Bucket> {
+            if self.closed.is_some() {
+                panic!("This collection is permanently closed.");
+            }
+
+            let closed =
+                UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap();
+
+            // An all-or-nothing campaign that has not succeeded is cancelled by closing it, and
+            // the donations are kept in escrow for the backers to be refunded.
+            let cancelled = self.is_all_or_nothing()
+                && !self.settled
+                && !(self.campaign_ended() && self.goal_reached);
+
+            self.closed = Some(closed);
+
+            Runtime::emit_event(CollectionClosedEvent {
+                collection_id: self.collection_id.clone(),
+                closed,
+                cancelled,
+            });
+
+            if cancelled {
+                return vec![];
+            }
+
+            // Donations split between payees are allocated to them, and claimed by each payee.
+            if !self.splits.is_empty() {
+                self.settle_campaign();
+                self.allocate_splits();
+                return vec![];
+            }
+
+            // Withdraw the remaining donations, vested donations are withdrawn as they unlock.
+            self.withdraw_donations()
+        }
+
+        // pause_collection is a method for the collection owner to pause the collection
+        // temporarily. Donations are not accepted until the collection is resumed.
+        pub fn pause_collection(&mut self) {
+            self.pause(false);
+        }
+
+        // admin_pause_collection is a method for the repository owner to pause the collection
+        // temporarily, such as during a dispute. Such a pause can only be resumed by the repository
+        // owner.
+        pub fn admin_pause_collection(&mut self) {
+            self.pause(true);
+        }
+
+        // resume_collection is a method for the collection owner or the repository owner to resume
+        // a paused collection, accepting donations again. A pause by the repository owner can only
+        // be resumed by the repository owner.
+        pub fn resume_collection(&mut self) {
+            assert!(self.paused.is_some(), "This collection is not paused.");
+
+            if self.paused_by_repository_owner {
+                Runtime::assert_access_rule(rule!(require(
+                    self.repository_owner_access_badge_address
+                )));
+            }
+
+            let resumed =
+                UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap();
+
+            self.paused = None;
+            self.paused_by_repository_owner = false;
+
+            Runtime::emit_event(CollectionResumedEvent {
+                collection_id: self.collection_id.clone(),
+                resumed,
+            });
+        }
+
+        // get_paused returns the date the collection was paused, or None if it is not paused.
+        pub fn get_paused(&self) -> Option<UtcDateTime> {
+            self.paused
+        }
+
+        // refund is a public method for backers of a failed all-or-nothing campaign to reclaim
+        // what they donated. The backer presents a trophy proof, and returns the thanks tokens
+        // received for the donations. The trophy is marked as refunded.
+        pub fn refund(&mut self, trophy_proof: Proof, thanks: Bucket) -> Vec<Bucket> {
+            assert!(
+                self.is_refundable(),
+                "Donations to this collection can not be refunded."
+            );
+
+            // Check that the proof is of same resource address.
+            let checked_trophy_proof = trophy_proof.check(self.trophy_resource_manager.address());
+            let nft_id = checked_trophy_proof
+                .as_non_fungible()
+                .non_fungible_local_id();
+
+            let data: Trophy = self.trophy_resource_manager.get_non_fungible_data(&nft_id);
+
+            assert_eq!(
+                data.collection_id, self.collection_id,
+                "The given trophy does not match the collection id of this component."
+            );
+
+            assert!(
+                !data.refunded,
+                "The given trophy has already been refunded."
+            );
+
+            // Sum up the donated and the matched amount of each resource.
+            let mut amounts: Vec<(ResourceAddress, Decimal)> = vec![];
+            let mut matched: Vec<(ResourceAddress, Decimal)> = vec![];
+            for transaction in data.transactions.iter() {
+                add_amount(
+                    &mut amounts,
+                    transaction.resource_address,
+                    transaction.amount,
+                );
+                add_amount(
+                    &mut matched,
+                    transaction.resource_address,
+                    transaction.matched,
+                );
+            }
+
+            // Only the donated amount was counted for the thanks tokens and the creator.
+            let amount = data.donated;
+
+            // Push a proof of minter badge to the local auth zone for burning thanks tokens and
+            // updating the trophy.
+            LocalAuthZone::push(self.minter_badge.as_fungible().create_proof_of_amount(1));
+
+            assert_eq!(
+                thanks.resource_address(),
+                self.thanks_token_resource_manager.address(),
+                "The given thanks tokens is not the of the same resource type as managed by the repository."
+            );
+
+            assert!(
+                thanks.amount() <= amount,
+                "More thanks tokens than received for the donations can not be returned."
+            );
+
+            // Thanks tokens that has been spent, such as on perks, are withheld from the refund of
+            // the counted resource, and taken as fees.
+            let withheld = amount - thanks.amount();
+            thanks.burn();
+
+            self.trophy_resource_manager
+                .update_non_fungible_data(&nft_id, "refunded", true);
+
+            self.update_creator_metadata(-amount);
+
+            for (resource_address, amount) in amounts.iter() {
+                if self.is_raised_resource(resource_address) {
+                    self.raised -= *amount;
+                }
+            }
+
+            // The matches of the donations are returned to the matching pool.
+            for (resource_address, amount) in matched {
+                if amount == dec!(0) {
+                    continue;
+                }
+
+                let returned = self
+                    .donations
+                    .get_mut(&resource_address)
+                    .unwrap()
+                    .take(amount);
+                self.matching_pool
+                    .get_mut(&resource_address)
+                    .unwrap()
+                    .put(returned);
+
+                let matched = self.get_matched(&resource_address) - amount;
+                self.matched.insert(resource_address, matched);
+
+                if self.is_raised_resource(&resource_address) {
+                    self.raised -= amount;
+                }
+            }
+
+            if withheld > dec!(0) {
+                let fees = self
+                    .donations
+                    .get_mut(&self.counted_resource)
+                    .unwrap()
+                    .take(withheld);
+                self.fees.get_mut(&self.counted_resource).unwrap().put(fees);
+            }
+
+            Runtime::emit_event(RefundEvent {
+                collection_id: self.collection_id.clone(),
+                trophy_id: nft_id,
+                amount,
+                withheld,
+            });
+
+            amounts
+                .into_iter()
+                .map(|(resource_address, amount)| {
+                    let amount = if self.is_raised_resource(&resource_address) {
+                        amount - withheld
+                    } else {
+                        amount
+                    };
+
+                    self.donations
+                        .get_mut(&resource_address)
+                        .unwrap()
+                        .take(amount)
+                })
+                .collect()
+        }
+
+        // set_fee_rate is a method for the repository owner to override the fee rate of this
+        // collection. Setting it to None makes the collection use the repository fee rate.
+        pub fn set_fee_rate(&mut self, fee_rate: Option<Decimal>) {
+            if let Some(fee_rate) = fee_rate {
+                validate_fee_rate(fee_rate);
+            }
+
+            self.fee_rate = fee_rate;
+
+            Runtime::emit_event(FeeRateChangedEvent {
+                collection_id: Some(self.collection_id.clone()),
+                fee_rate,
+            });
+        }
+
+        // get_fee_rate returns the fee rate that is taken from donations to this collection.
+        pub fn get_fee_rate(&self) -> Decimal {
+            match self.fee_rate {
+                Some(fee_rate) => fee_rate,
+                None => self.repository.get_fee_rate(),
+            }
+        }
+
+        // set_donation_limits is a method for the collection owner to override the minimum and
+        // maximum donation of a resource in this collection. Setting it to None makes the
+        // collection use the repository donation limits of the resource.
+        pub fn set_donation_limits(
+            &mut self,
+            resource_address: ResourceAddress,
+            donation_limits: Option<DonationLimits>,
+        ) {
+            match &donation_limits {
+                Some(donation_limits) => {
+                    validate_donation_limits(donation_limits);
+                    self.donation_limits
+                        .insert(resource_address, donation_limits.clone());
+                }
+                None => {
+                    self.donation_limits.remove(&resource_address);
+                }
+            }
+
+            Runtime::emit_event(DonationLimitsChangedEvent {
+                collection_id: Some(self.collection_id.clone()),
+                resource_address,
+                donation_limits,
+            });
+        }
+
+        // get_donation_limits returns the minimum and maximum donation of a resource in this
+        // collection, or None if donations in the resource are not accepted for lack of limits.
+        pub fn get_donation_limits(
+            &self,
+            resource_address: ResourceAddress,
+        ) -> Option<DonationLimits> {
+            match self.donation_limits.get(&resource_address) {
+                Some(donation_limits) => Some(donation_limits.clone()),
+                None => self.repository.get_donation_limits(resource_address),
+            }
+        }
+
+        // get_counted_resource returns the resource that donations are counted in towards the
+        // trophies, memberships, thanks tokens and the amount raised.
+        pub fn get_counted_resource(&self) -> ResourceAddress {
+            self.counted_resource
+        }
+
+        // add_accepted_resource is a method for the repository owner to accept donations in
+        // another fungible resource.
+        pub fn add_accepted_resource(&mut self, resource_address: ResourceAddress) {
+            assert!(
+                ResourceManager::from(resource_address)
+                    .resource_type()
+                    .is_fungible(),
+                "Only fungible resources can be accepted as donation."
+            );
+
+            assert!(
+                !self.accepted_resources.contains(&resource_address),
+                "The given resource is already accepted by this collection."
+            );
+
+            self.accepted_resources.push(resource_address);
+
+            Runtime::emit_event(AcceptedResourceAddedEvent {
+                collection_id: self.collection_id.clone(),
+                resource_address,
+            });
+
+            // Resources that has been accepted before still has their vaults.
+            if !self.resources.contains(&resource_address) {
+                self.resources.push(resource_address);
+                self.donations
+                    .insert(resource_address, Vault::new(resource_address));
+                self.fees
+                    .insert(resource_address, Vault::new(resource_address));
+            }
+        }
+
+        // remove_accepted_resource is a method for the repository owner to stop accepting
+        // donations in a resource. Donations and fees already collected in the resource can
+        // still be withdrawn.
+        pub fn remove_accepted_resource(&mut self, resource_address: ResourceAddress) {
+            assert!(
+                self.accepted_resources.contains(&resource_address),
+                "The given resource is not accepted by this collection."
+            );
+
+            self.accepted_resources
+                .retain(|accepted| *accepted != resource_address);
+
+            Runtime::emit_event(AcceptedResourceRemovedEvent {
+                collection_id: self.collection_id.clone(),
+                resource_address,
+            });
+        }
+
+        // set_tiers is a method for the collection owner to define the donation tiers of the
+        // collection. Trophies get their tier when minted or updated with a new donation.
+        pub fn set_tiers(&mut self, tiers: Vec<Tier>) {
+            validate_tiers(&tiers);
+            self.tiers = tiers.clone();
+
+            Runtime::emit_event(TiersChangedEvent {
+                collection_id: self.collection_id.clone(),
+                tiers,
+            });
+        }
+
+        // get_tiers returns the donation tiers of the collection.
+        pub fn get_tiers(&self) -> Vec<Tier> {
+            self.tiers.clone()
+        }
+
+        // update_trophy_details is a method for the collection owner to change the name and
+        // description of the trophies minted by this collection. Existing trophies keep their
+        // details until they are re-stamped.
+        pub fn update_trophy_details(&mut self, trophy_name: String, trophy_description: String) {
             if self.closed.is_some() {
                 panic!("This collection is permanently closed.");
             }
 
-            self.closed =
-                Some(UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap());
+            validate_trophy_details(&trophy_name, &trophy_description);
 
-            // Withdraw all remaining donations.
-            self.donations.take_all()
+            self.trophy_name = trophy_name.clone();
+            self.trophy_description = trophy_description.clone();
+
+            Runtime::emit_event(TrophyDetailsUpdatedEvent {
+                collection_id: self.collection_id.clone(),
+                trophy_name,
+                trophy_description,
+            });
+        }
+
+        // restamp_trophies is a method for the collection owner to apply the current name and
+        // description of the collection to existing trophies of this collection.
+        pub fn restamp_trophies(&mut self, trophy_ids: Vec<NonFungibleLocalId>) {
+            // Push a proof of minter badge to the local auth zone for updating the trophies.
+            LocalAuthZone::push(self.minter_badge.as_fungible().create_proof_of_amount(1));
+
+            for nft_id in trophy_ids.iter() {
+                let data: Trophy = self.trophy_resource_manager.get_non_fungible_data(nft_id);
+
+                assert_eq!(
+                    data.collection_id, self.collection_id,
+                    "The given trophy does not match the collection id of this component."
+                );
+
+                self.trophy_resource_manager.update_non_fungible_data(
+                    nft_id,
+                    "name",
+                    self.trophy_name.clone(),
+                );
+                self.trophy_resource_manager.update_non_fungible_data(
+                    nft_id,
+                    "description",
+                    self.trophy_description.clone(),
+                );
+            }
+
+            Runtime::emit_event(TrophiesRestampedEvent {
+                collection_id: self.collection_id.clone(),
+                trophy_ids,
+            });
+        }
+
+        // set_creator is a method for the repository to hand the ownership of the collection to
+        // another creator badge, when creator badges are merged.
+        pub fn set_creator(&mut self, creator_badge_global_id: NonFungibleGlobalId) {
+            self.creator_badge_global_id = creator_badge_global_id.clone();
+
+            Runtime::global_component()
+                .set_role("owner", rule!(require(creator_badge_global_id.clone())));
+
+            Runtime::emit_event(CollectionCreatorChangedEvent {
+                collection_id: self.collection_id.clone(),
+                creator: creator_badge_global_id,
+            });
+        }
+
+        // get_funding_progress returns the amount raised by the collection, and the progress
+        // towards the funding goal if one is set.
+        pub fn get_funding_progress(&self) -> FundingProgress {
+            match &self.funding_goal {
+                Some(funding_goal) => FundingProgress {
+                    raised: self.raised,
+                    goal: Some(funding_goal.amount),
+                    percentage: Some(self.raised / funding_goal.amount * dec!(100)),
+                    deadline: funding_goal.deadline,
+                    goal_reached: self.goal_reached,
+                },
+                None => FundingProgress {
+                    raised: self.raised,
+                    goal: None,
+                    percentage: None,
+                    deadline: None,
+                    goal_reached: false,
+                },
+            }
         }
     }
 }
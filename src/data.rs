@@ -3,13 +3,21 @@ use scrypto::prelude::*;
 #[derive(ScryptoSbor, NonFungibleData, Clone)]
 pub struct Transaction {
     pub amount: Decimal,
+    pub resource_address: ResourceAddress,
     pub created: String,
+    pub message: Option<String>,
+    pub matched: Decimal,
+    pub gifter: Option<NonFungibleGlobalId>,
 }
 
 #[derive(ScryptoSbor, NonFungibleData, Clone)]
 pub struct Trophy {
+    #[mutable]
     pub name: String,
+
+    #[mutable]
     pub description: String,
+
     pub creator: NonFungibleGlobalId,
     pub creator_name: String,
     pub creator_slug: String,
@@ -25,6 +33,12 @@ pub struct Trophy {
 
     #[mutable]
     pub key_image_url: UncheckedUrl,
+
+    #[mutable]
+    pub refunded: bool,
+
+    #[mutable]
+    pub tier: Option<String>,
 }
 
 #[derive(ScryptoSbor, NonFungibleData, Clone)]
@@ -45,14 +59,28 @@ pub struct Membership {
 
     #[mutable]
     pub key_image_url: UncheckedUrl,
+
+    #[mutable]
+    pub expires_at: Option<Instant>,
+
+    #[mutable]
+    pub level: Option<String>,
 }
 
 #[derive(ScryptoSbor, NonFungibleData, Clone)]
 pub struct Creator {
+    #[mutable]
     pub name: String,
+
+    #[mutable]
     pub description: String,
+
+    #[mutable]
     pub creator_name: String,
+
+    #[mutable]
     pub creator_slug: String,
+
     pub created: String,
 
     #[mutable]
@@ -61,3 +89,93 @@ pub struct Creator {
     #[mutable]
     pub key_image_url: UncheckedUrl,
 }
+
+#[derive(ScryptoSbor, NonFungibleData, Clone)]
+pub struct RedemptionReceipt {
+    pub name: String,
+    pub description: String,
+    pub perk_id: u64,
+    pub creator: NonFungibleGlobalId,
+    pub price: Decimal,
+    pub created: String,
+
+    #[mutable]
+    pub fulfilled: bool,
+}
+
+#[derive(ScryptoSbor, Clone)]
+pub struct Perk {
+    pub creator: NonFungibleGlobalId,
+    pub name: String,
+    pub description: String,
+    pub price: Decimal,
+    pub stock: u64,
+    pub redeemed: u64,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone)]
+pub struct FundingGoal {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+    pub deadline: Option<Instant>,
+    pub all_or_nothing: bool,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone)]
+pub struct DonationLimits {
+    pub minimum: Decimal,
+    pub maximum: Option<Decimal>,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone)]
+pub struct Tier {
+    pub name: String,
+    pub threshold: Decimal,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, PartialEq, Eq)]
+pub enum Payee {
+    Badge(NonFungibleGlobalId),
+    Account(ComponentAddress),
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone)]
+pub struct Split {
+    pub payee: Payee,
+    pub share: u16,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone)]
+pub struct Subscription {
+    pub resource_address: ResourceAddress,
+    pub price: Decimal,
+    pub days: u32,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone)]
+pub struct Matching {
+    pub ratio: Decimal,
+    pub cap: Decimal,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone)]
+pub struct Milestone {
+    pub description: String,
+    pub share: u16,
+    pub approved: bool,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone)]
+pub enum Vesting {
+    Linear { start: Instant, days: u32 },
+    Milestones(Vec<Milestone>),
+}
+
+#[derive(ScryptoSbor, Clone)]
+pub struct FundingProgress {
+    pub raised: Decimal,
+    pub goal: Option<Decimal>,
+    pub percentage: Option<Decimal>,
+    pub deadline: Option<Instant>,
+    pub goal_reached: bool,
+}
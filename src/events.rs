@@ -0,0 +1,352 @@
+use crate::data::{DonationLimits, Matching, Payee, Split, Subscription, Tier, Vesting};
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DonationEvent {
+    pub collection_id: String,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+    pub matched: Decimal,
+    pub fee: Decimal,
+    pub trophy_id: Option<NonFungibleLocalId>,
+    pub membership_id: NonFungibleLocalId,
+    pub message: Option<String>,
+    pub gifter: Option<NonFungibleGlobalId>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct GiftEvent {
+    pub collection_id: String,
+    pub trophy_id: NonFungibleLocalId,
+    pub membership_id: NonFungibleLocalId,
+    pub gifter: NonFungibleGlobalId,
+    pub recipient: ComponentAddress,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct TrophyMintedEvent {
+    pub collection_id: String,
+    pub trophy_id: NonFungibleLocalId,
+    pub donated: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct TrophyUpdatedEvent {
+    pub collection_id: String,
+    pub trophy_id: NonFungibleLocalId,
+    pub donated: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct TrophyDetailsUpdatedEvent {
+    pub collection_id: String,
+    pub trophy_name: String,
+    pub trophy_description: String,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct TrophiesRestampedEvent {
+    pub collection_id: String,
+    pub trophy_ids: Vec<NonFungibleLocalId>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct TrophyMergedEvent {
+    pub collection_id: String,
+    pub merged_ids: Vec<NonFungibleLocalId>,
+    pub trophy_id: NonFungibleLocalId,
+    pub donated: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct TrophySplitEvent {
+    pub collection_id: String,
+    pub trophy_id: NonFungibleLocalId,
+    pub split_ids: Vec<NonFungibleLocalId>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MembershipMintedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub membership_id: NonFungibleLocalId,
+    pub donated: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MembershipUpdatedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub membership_id: NonFungibleLocalId,
+    pub donated: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MembershipRenewedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub membership_id: NonFungibleLocalId,
+    pub amount: Decimal,
+    pub expires_at: Option<Instant>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MembershipLevelsChangedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub levels: Vec<Tier>,
+}
+
+// SubscriptionChangedEvent is emitted when a creator changes the membership subscription, the
+// subscription is not set when the subscription mode is turned off.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct SubscriptionChangedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub subscription: Option<Subscription>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MembershipMergedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub merged_ids: Vec<NonFungibleLocalId>,
+    pub membership_id: NonFungibleLocalId,
+    pub donated: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CreatorBadgeMintedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub creator_name: String,
+    pub creator_slug: String,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CreatorProfileUpdatedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub creator_name: String,
+    pub creator_slug: String,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CreatorBadgesMergedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub merged: Vec<NonFungibleGlobalId>,
+    pub funded: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CollectionCreatorChangedEvent {
+    pub collection_id: String,
+    pub creator: NonFungibleGlobalId,
+}
+
+// CreatorSlugChangedEvent is emitted when the admin releases or reassigns a creator slug, the
+// creator is not set when the slug is released.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CreatorSlugChangedEvent {
+    pub creator_slug: String,
+    pub creator: Option<NonFungibleGlobalId>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CollectionCreatedEvent {
+    pub collection_id: String,
+    pub creator: NonFungibleGlobalId,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CollectionClosedEvent {
+    pub collection_id: String,
+    pub closed: UtcDateTime,
+    pub cancelled: bool,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CollectionPausedEvent {
+    pub collection_id: String,
+    pub paused: UtcDateTime,
+    pub by_repository_owner: bool,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CollectionResumedEvent {
+    pub collection_id: String,
+    pub resumed: UtcDateTime,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct RepositoryClosedEvent {
+    pub closed: UtcDateTime,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct EmergencyPausedEvent {
+    pub paused: UtcDateTime,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct EmergencyUnpausedEvent {
+    pub unpaused: UtcDateTime,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct WithdrawalEvent {
+    pub collection_id: String,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MatchingChangedEvent {
+    pub collection_id: String,
+    pub matching: Option<Matching>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MatchingPoolFundedEvent {
+    pub collection_id: String,
+    pub sponsor: NonFungibleGlobalId,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MatchingPoolReclaimedEvent {
+    pub collection_id: String,
+    pub sponsor: NonFungibleGlobalId,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct VestingSetEvent {
+    pub collection_id: String,
+    pub vesting: Vesting,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MilestoneApprovedEvent {
+    pub collection_id: String,
+    pub index: u32,
+    pub description: String,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AutoPayoutChangedEvent {
+    pub collection_id: String,
+    pub account: Option<ComponentAddress>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DonationPaidOutEvent {
+    pub collection_id: String,
+    pub account: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct SplitsChangedEvent {
+    pub collection_id: String,
+    pub splits: Vec<Split>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ShareClaimedEvent {
+    pub collection_id: String,
+    pub payee: Payee,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct FeeWithdrawalEvent {
+    pub collection_id: String,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CampaignSettledEvent {
+    pub collection_id: String,
+    pub raised: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct RefundEvent {
+    pub collection_id: String,
+    pub trophy_id: NonFungibleLocalId,
+    pub amount: Decimal,
+    pub withheld: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ThanksRedeemedEvent {
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PerkAddedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub perk_id: u64,
+    pub price: Decimal,
+    pub stock: u64,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PerkStockChangedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub perk_id: u64,
+    pub stock: u64,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PerkRedeemedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub perk_id: u64,
+    pub receipt_id: NonFungibleLocalId,
+    pub price: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct RedemptionFulfilledEvent {
+    pub creator: NonFungibleGlobalId,
+    pub receipt_id: NonFungibleLocalId,
+}
+
+// FeeRateChangedEvent is emitted by both blueprints, the collection id is not set when the
+// repository fee rate is changed.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct FeeRateChangedEvent {
+    pub collection_id: Option<String>,
+    pub fee_rate: Option<Decimal>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CreatorFeeRateChangedEvent {
+    pub creator: NonFungibleGlobalId,
+    pub fee_rate: Option<Decimal>,
+}
+
+// DonationLimitsChangedEvent is emitted by both blueprints, the collection id is not set when the
+// repository donation limits are changed.
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct DonationLimitsChangedEvent {
+    pub collection_id: Option<String>,
+    pub resource_address: ResourceAddress,
+    pub donation_limits: Option<DonationLimits>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct TiersChangedEvent {
+    pub collection_id: String,
+    pub tiers: Vec<Tier>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AcceptedResourceAddedEvent {
+    pub collection_id: String,
+    pub resource_address: ResourceAddress,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AcceptedResourceRemovedEvent {
+    pub collection_id: String,
+    pub resource_address: ResourceAddress,
+}
@@ -1,36 +1,137 @@
 use crate::collection::collection::Collection;
-use crate::data::{Creator, Membership, Transaction, Trophy};
+use crate::data::{
+    Creator, DonationLimits, FundingGoal, Membership, Perk, RedemptionReceipt, Subscription, Tier,
+    Transaction, Trophy,
+};
+use crate::events::{
+    CollectionCreatedEvent, CreatorBadgeMintedEvent, CreatorBadgesMergedEvent,
+    CreatorFeeRateChangedEvent, CreatorProfileUpdatedEvent, CreatorSlugChangedEvent,
+    DonationLimitsChangedEvent, EmergencyPausedEvent, EmergencyUnpausedEvent, FeeRateChangedEvent,
+    MembershipLevelsChangedEvent, MembershipMergedEvent, PerkAddedEvent, PerkRedeemedEvent,
+    PerkStockChangedEvent, RedemptionFulfilledEvent, RepositoryClosedEvent,
+    SubscriptionChangedEvent, ThanksRedeemedEvent, TrophyMergedEvent, TrophySplitEvent,
+};
 use crate::util::*;
 use scrypto::prelude::*;
 
 #[blueprint]
-#[types(Trophy, Membership, Creator, Transaction)]
+#[types(Trophy, Membership, Creator, Transaction, RedemptionReceipt, Perk)]
+#[events(
+    CollectionCreatedEvent,
+    CreatorBadgeMintedEvent,
+    CreatorProfileUpdatedEvent,
+    CreatorBadgesMergedEvent,
+    CreatorSlugChangedEvent,
+    TrophyMergedEvent,
+    TrophySplitEvent,
+    MembershipMergedEvent,
+    ThanksRedeemedEvent,
+    PerkAddedEvent,
+    PerkStockChangedEvent,
+    PerkRedeemedEvent,
+    RedemptionFulfilledEvent,
+    RepositoryClosedEvent,
+    EmergencyPausedEvent,
+    EmergencyUnpausedEvent,
+    FeeRateChangedEvent,
+    CreatorFeeRateChangedEvent,
+    DonationLimitsChangedEvent,
+    SubscriptionChangedEvent,
+    MembershipLevelsChangedEvent
+)]
 mod repository {
     use crate::collection::CollectionArg;
     use std::cmp::Ordering;
     enable_package_royalties! {
         new => Free;
         merge_trophies => Free;
+        split_trophy => Free;
         merge_memberships => Free;
         new_collection_component => Usd(5.into());
         new_collection_component_and_badge => Usd(5.into());
         mint_creator_badge => Free;
+        update_creator_profile => Free;
+        merge_creator_badges => Free;
         redeem_thanks_token => Free;
+        add_perk => Free;
+        set_perk_stock => Free;
+        get_perk => Free;
+        get_perks => Free;
+        redeem_perk => Free;
+        fulfil_redemption => Free;
+        get_outstanding_redemptions => Free;
         close_repository => Free;
+        set_fee_rate => Free;
+        get_fee_rate => Free;
+        set_creator_fee_rate => Free;
+        get_creator_fee_rate => Free;
+        set_donation_limits => Free;
+        get_donation_limits => Free;
+        get_collections => Free;
+        get_collection_creator => Free;
+        is_collection => Free;
+        release_creator_slug => Free;
+        reassign_creator_slug => Free;
+        get_creator_slug_owner => Free;
+        get_closed => Free;
+        emergency_pause => Free;
+        emergency_unpause => Free;
+        get_emergency_paused => Free;
+        set_subscription => Free;
+        get_subscription => Free;
+        is_membership_active => Free;
+        set_membership_levels => Free;
+        get_membership_levels => Free;
+        approve_milestone => Free;
+        resolve_creator => Free;
     }
 
     enable_method_auth! {
         roles {
             admin => updatable_by: [OWNER];
+            emergency => updatable_by: [OWNER];
         },
         methods {
             new_collection_component => PUBLIC;
             new_collection_component_and_badge => PUBLIC;
             mint_creator_badge => PUBLIC;
+            update_creator_profile => PUBLIC;
+            merge_creator_badges => PUBLIC;
             merge_trophies => PUBLIC;
+            split_trophy => PUBLIC;
             merge_memberships => PUBLIC;
             redeem_thanks_token => PUBLIC;
+            add_perk => PUBLIC;
+            set_perk_stock => PUBLIC;
+            get_perk => PUBLIC;
+            get_perks => PUBLIC;
+            redeem_perk => PUBLIC;
+            fulfil_redemption => PUBLIC;
+            get_outstanding_redemptions => PUBLIC;
             close_repository => restrict_to: [admin];
+            set_fee_rate => restrict_to: [admin];
+            get_fee_rate => PUBLIC;
+            set_creator_fee_rate => restrict_to: [admin];
+            get_creator_fee_rate => PUBLIC;
+            set_donation_limits => restrict_to: [admin];
+            get_donation_limits => PUBLIC;
+            get_collections => PUBLIC;
+            get_collection_creator => PUBLIC;
+            is_collection => PUBLIC;
+            release_creator_slug => restrict_to: [admin];
+            reassign_creator_slug => restrict_to: [admin];
+            get_creator_slug_owner => PUBLIC;
+            get_closed => PUBLIC;
+            emergency_pause => restrict_to: [emergency];
+            emergency_unpause => restrict_to: [emergency];
+            get_emergency_paused => PUBLIC;
+            set_subscription => PUBLIC;
+            get_subscription => PUBLIC;
+            is_membership_active => PUBLIC;
+            set_membership_levels => PUBLIC;
+            get_membership_levels => PUBLIC;
+            approve_milestone => restrict_to: [admin];
+            resolve_creator => PUBLIC;
         }
     }
 
@@ -58,12 +159,59 @@ mod repository {
 
         // Closed date for the collection
         closed: Option<UtcDateTime>,
+
+        // Emergency pause date, donations and minting are halted in all collections while set
+        emergency_paused: Option<UtcDateTime>,
+
+        // Fee rate taken from donations in collections that don't override it
+        fee_rate: Decimal,
+
+        // Fee rate overrides for the collections of a creator, keyed by the creator badge
+        creator_fee_rates: KeyValueStore<NonFungibleGlobalId, Decimal>,
+
+        // Donation limits per resource in collections that don't override them, donations in a
+        // resource without limits are not accepted
+        donation_limits: KeyValueStore<ResourceAddress, DonationLimits>,
+
+        // Collections instantiated by the repository, keyed by the creator badge that owns them
+        collections: KeyValueStore<NonFungibleGlobalId, Vec<ComponentAddress>>,
+
+        // Creator badge that owns a collection, keyed by the collection address
+        collection_creators: KeyValueStore<ComponentAddress, NonFungibleGlobalId>,
+
+        // Creator slugs reserved by creator badges, a slug can only be held by one creator
+        creator_slugs: KeyValueStore<String, NonFungibleGlobalId>,
+
+        // Receipt resource manager, receipts are minted when backers redeem perks.
+        redemption_receipt_resource_manager: ResourceManager,
+
+        // Perks that can be redeemed with thanks tokens, keyed by the perk id
+        perks: KeyValueStore<u64, Perk>,
+
+        // Id of the next perk
+        next_perk_id: u64,
+
+        // Perks listed by creators, keyed by the creator badge
+        creator_perks: KeyValueStore<NonFungibleGlobalId, Vec<u64>>,
+
+        // Receipts of redemptions that has not been fulfilled, keyed by the creator badge
+        outstanding_redemptions: KeyValueStore<NonFungibleGlobalId, Vec<NonFungibleLocalId>>,
+
+        // Membership subscriptions offered by creators, keyed by the creator badge
+        subscriptions: KeyValueStore<NonFungibleGlobalId, Subscription>,
+
+        // Membership levels ordered by ascending thresholds, keyed by the creator badge
+        membership_levels: KeyValueStore<NonFungibleGlobalId, Vec<Tier>>,
+
+        // Creator badge that a creator badge was merged into, keyed by the merged badge
+        creator_aliases: KeyValueStore<NonFungibleGlobalId, NonFungibleGlobalId>,
     }
 
     impl Repository {
         pub fn new(
             base_path: String,
             repository_owner_access_badge_address: ResourceAddress,
+            emergency_access_badge_address: ResourceAddress,
             dapp_definition_address: GlobalAddress,
         ) -> Global<Repository> {
             let (address_reservation, component_address) =
@@ -232,6 +380,47 @@ mod repository {
                 ))
                 .create_with_no_initial_supply();
 
+            // Manager for minting receipts of redeemed perks. A receipt references the redeemed
+            // perk, and is marked as fulfilled by the creator that listed the perk.
+            let redemption_receipt_resource_manager = ResourceBuilder::new_ruid_non_fungible_with_registered_type::<RedemptionReceipt>(OwnerRole::Fixed(
+                rule!(require(repository_owner_access_badge_address))
+            ))
+                .metadata(metadata!(
+                    roles {
+                        metadata_setter => rule!(require(repository_owner_access_badge_address) || require(global_caller(component_address)));
+                        metadata_setter_updater => rule!(require(repository_owner_access_badge_address));
+                        metadata_locker => rule!(deny_all);
+                        metadata_locker_updater => rule!(deny_all);
+                    },
+                    init {
+                        "name" => "Backeum Redemption Receipts", locked;
+                        "description" => "Proof of redeeming a perk from a Backeum creator with thanks tokens. The creator marks the receipt as fulfilled when the perk has been delivered.", locked;
+                        "icon_url" => UncheckedUrl::of(format!("{}{}", base_path, "/bucket/assets/wallet-assets/receipt.png")), updatable;
+                        "tags" => vec!["backeum", "receipt"], locked;
+                        "info_url" => UncheckedUrl::of(base_path.clone()), locked;
+                        "dapp_definition" => dapp_definition_address, locked;
+                    }
+                ))
+                .mint_roles(mint_roles!(
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(require(repository_owner_access_badge_address));
+                ))
+                .non_fungible_data_update_roles(non_fungible_data_update_roles!(
+                    non_fungible_data_updater => rule!(require(global_caller(component_address)));
+                    non_fungible_data_updater_updater => rule!(require(repository_owner_access_badge_address));
+                ))
+                .create_with_no_initial_supply();
+
+            // Donations are accepted in XRD by default.
+            let donation_limits = KeyValueStore::new();
+            donation_limits.insert(
+                XRD,
+                DonationLimits {
+                    minimum: dec!(1),
+                    maximum: None,
+                },
+            );
+
             Self {
                 trophy_resource_manager,
                 thanks_token_resource_manager,
@@ -241,6 +430,21 @@ mod repository {
                 repository_owner_access_badge_address,
                 dapp_definition_address,
                 closed: None,
+                emergency_paused: None,
+                fee_rate: dec!(0.04),
+                creator_fee_rates: KeyValueStore::new(),
+                donation_limits,
+                collections: KeyValueStore::new(),
+                collection_creators: KeyValueStore::new(),
+                creator_slugs: KeyValueStore::new(),
+                redemption_receipt_resource_manager,
+                perks: KeyValueStore::new(),
+                next_perk_id: 1,
+                creator_perks: KeyValueStore::new(),
+                outstanding_redemptions: KeyValueStore::new(),
+                subscriptions: KeyValueStore::new(),
+                membership_levels: KeyValueStore::new(),
+                creator_aliases: KeyValueStore::new(),
             }
             .instantiate()
             .prepare_to_globalize(OwnerRole::Fixed(
@@ -259,57 +463,148 @@ mod repository {
             ))
             .roles(roles! {
                 admin => rule!(require(repository_owner_access_badge_address));
+                emergency => rule!(require(emergency_access_badge_address));
             })
             .with_address(address_reservation)
             .globalize()
         }
 
-        // new_collection_component sets up a new collection component for a user, and give that contract
-        // a mint badge that allows for it to create and update trophies. By going through Repository
-        // for instantiation we can ensure that the mint badge is only given to a contract that is
-        // made by Backeum.
-        pub fn new_collection_component(
+        // register_collection is a private method that adds a collection instantiated by the
+        // repository to the registry of the creator.
+        fn register_collection(
             &mut self,
-            creator_badge_proof: Proof,
-            trophy_name: String,
-            trophy_description: String,
-        ) -> Global<Collection> {
+            collection: ComponentAddress,
+            creator: NonFungibleGlobalId,
+        ) {
+            self.add_collection(collection, creator.clone());
+
+            Runtime::emit_event(CollectionCreatedEvent {
+                collection_id: Runtime::bech32_encode_address(collection),
+                creator,
+            });
+        }
+
+        // add_collection is a private method that adds a collection to the registry of the creator.
+        fn add_collection(&mut self, collection: ComponentAddress, creator: NonFungibleGlobalId) {
+            let mut collections = self
+                .collections
+                .get(&creator)
+                .map(|collections| collections.clone())
+                .unwrap_or_default();
+            collections.push(collection);
+
+            self.collections.insert(creator.clone(), collections);
+            self.collection_creators.insert(collection, creator);
+        }
+
+        // assert_open is a private method that panics if the repository has been closed.
+        fn assert_open(&self) {
             if self.closed.is_some() {
                 panic!("This repository is permanently closed.");
             }
+        }
 
-            assert!(
-                trophy_name.len() >= 3,
-                "Trophy name must be 3 characters or more."
-            );
+        // assert_not_emergency_paused is a private method that panics if the repository has been
+        // paused in an emergency.
+        fn assert_not_emergency_paused(&self) {
+            if self.emergency_paused.is_some() {
+                panic!("This repository is paused in an emergency.");
+            }
+        }
 
+        // assert_creator_slug is a private method that panics if the creator has no slug, as a
+        // released or reassigned slug leaves the creator without one until a new slug is set.
+        fn assert_creator_slug(&self, creator: &Creator) {
             assert!(
-                trophy_name.len() <= 32,
-                "Trophy name must not be over 32 characters"
+                !creator.creator_slug.is_empty(),
+                "The creator has no creator slug, a new one must be set."
             );
+        }
 
-            assert!(
-                trophy_description.len() >= 10,
-                "Trophy description must be 10 characters or more."
-            );
+        // get_creator is a private method that returns the current data of a creator badge, or of
+        // the badge it was merged into.
+        fn get_creator(&self, creator: &NonFungibleGlobalId) -> Creator {
+            self.creator_resource_manager
+                .get_non_fungible_data(self.resolve_creator(creator.clone()).local_id())
+        }
+
+        // get_collection is a private method that returns a collection of the creator by its
+        // collection id, or None if the collection is not found.
+        fn get_collection(
+            &self,
+            creator: &NonFungibleGlobalId,
+            collection_id: &str,
+        ) -> Option<Global<Collection>> {
+            self.get_collections(creator.clone())
+                .into_iter()
+                .find(|collection| Runtime::bech32_encode_address(*collection) == collection_id)
+                .map(Global::<Collection>::from)
+        }
+
+        // get_collection_tiers is a private method that returns the donation tiers of a collection
+        // of the creator, no tiers are returned if the collection is not found.
+        fn get_collection_tiers(
+            &self,
+            creator: &NonFungibleGlobalId,
+            collection_id: &str,
+        ) -> Vec<Tier> {
+            self.get_collection(creator, collection_id)
+                .map_or(vec![], |collection| collection.get_tiers())
+        }
+
+        // check_creator_badge_proof is a private method that checks a creator badge proof, and
+        // returns the global id of the creator badge.
+        fn check_creator_badge_proof(&self, creator_badge_proof: Proof) -> NonFungibleGlobalId {
+            let checked_creator_badge_proof =
+                creator_badge_proof.check(self.creator_resource_manager.address());
 
+            NonFungibleGlobalId::new(
+                self.creator_resource_manager.address(),
+                checked_creator_badge_proof
+                    .as_non_fungible()
+                    .non_fungible_local_id(),
+            )
+        }
+
+        // reserve_creator_slug is a private method that reserves a creator slug for a creator
+        // badge. Slugs that are already held by a creator can not be reserved.
+        fn reserve_creator_slug(&mut self, creator_slug: String, creator: NonFungibleGlobalId) {
             assert!(
-                trophy_description.len() <= 255,
-                "Trophy description must not be over 255 characters"
+                self.creator_slugs.get(&creator_slug).is_none(),
+                "Creator slug is already taken."
             );
 
+            self.creator_slugs.insert(creator_slug, creator);
+        }
+
+        // new_collection_component sets up a new collection component for a user, and give that contract
+        // a mint badge that allows for it to create and update trophies. By going through Repository
+        // for instantiation we can ensure that the mint badge is only given to a contract that is
+        // made by Backeum.
+        pub fn new_collection_component(
+            &mut self,
+            creator_badge_proof: Proof,
+            trophy_name: String,
+            trophy_description: String,
+            funding_goal: Option<FundingGoal>,
+        ) -> Global<Collection> {
+            self.assert_open();
+
+            validate_trophy_details(&trophy_name, &trophy_description);
+
             let checked_creator_badge_proof =
                 creator_badge_proof.check(self.creator_resource_manager.address());
 
-            let data: Creator = self.creator_resource_manager.get_non_fungible_data(
-                &checked_creator_badge_proof
+            let creator = NonFungibleGlobalId::new(
+                checked_creator_badge_proof.resource_address(),
+                checked_creator_badge_proof
                     .as_non_fungible()
                     .non_fungible_local_id(),
             );
 
             let minter_badge = self.minter_badge_manager.mint(1);
 
-            Collection::new(CollectionArg {
+            let collection = Collection::new(CollectionArg {
                 trophy_resource_manager: self.trophy_resource_manager,
                 thanks_token_resource_manager: self.thanks_token_resource_manager,
                 membership_resource_manager: self.membership_resource_manager,
@@ -317,12 +612,17 @@ mod repository {
                 creator_resource_manager: self.creator_resource_manager,
                 creator_badge_proof: checked_creator_badge_proof.clone(),
                 minter_badge,
-                creator_name: data.creator_name,
-                creator_slug: data.creator_slug,
                 trophy_name,
                 trophy_description,
                 dapp_definition_address: self.dapp_definition_address,
-            })
+                repository: Global::from(Runtime::global_address()),
+                fee_rate: self.get_creator_fee_rate(creator.clone()),
+                funding_goal,
+            });
+
+            self.register_collection(collection.address(), creator);
+
+            collection
         }
 
         // new_collection_component_and_badge sets up a new collection component for a user, and
@@ -336,52 +636,15 @@ mod repository {
             creator_slug: String,
             trophy_name: String,
             trophy_description: String,
+            funding_goal: Option<FundingGoal>,
         ) -> (Global<Collection>, Bucket) {
-            if self.closed.is_some() {
-                panic!("This repository is permanently closed.");
-            }
-
-            assert_ne!(
-                creator_name.len(),
-                0,
-                "Creator name must be 1 character or more."
-            );
-
-            assert!(
-                creator_name.len() <= 255,
-                "Creator name must not be over 255 characters"
-            );
-
-            assert_ne!(
-                creator_slug.len(),
-                0,
-                "Creator slug must be 1 character or more."
-            );
+            self.assert_open();
 
-            assert!(
-                creator_slug.len() <= 30,
-                "Creator slug must not be over 30 characters"
-            );
+            validate_creator_name(&creator_name);
 
-            assert!(
-                trophy_name.len() >= 3,
-                "Trophy name must be 3 characters or more."
-            );
+            validate_creator_slug(&creator_slug);
 
-            assert!(
-                trophy_name.len() <= 32,
-                "Trophy name must not be over 32 characters"
-            );
-
-            assert!(
-                trophy_description.len() >= 10,
-                "Trophy description must be 10 characters or more."
-            );
-
-            assert!(
-                trophy_description.len() <= 255,
-                "Trophy description must not be over 255 characters"
-            );
+            validate_trophy_details(&trophy_name, &trophy_description);
 
             // Get the domain name used from the trophy resource manager.
             let domain: String = self
@@ -412,54 +675,54 @@ mod repository {
                     )),
                 });
 
+            let creator = NonFungibleGlobalId::new(
+                self.creator_resource_manager.address(),
+                creator_badge.as_non_fungible().non_fungible_local_id(),
+            );
+
+            self.reserve_creator_slug(creator_slug.clone(), creator.clone());
+
+            Runtime::emit_event(CreatorBadgeMintedEvent {
+                creator: creator.clone(),
+                creator_name,
+                creator_slug,
+            });
+
             let minter_badge = self.minter_badge_manager.mint(1);
 
-            (
-                Collection::new(CollectionArg {
-                    trophy_resource_manager: self.trophy_resource_manager,
-                    thanks_token_resource_manager: self.thanks_token_resource_manager,
-                    membership_resource_manager: self.membership_resource_manager,
-                    creator_resource_manager: self.creator_resource_manager,
-                    repository_owner_access_badge_address: self
-                        .repository_owner_access_badge_address,
-                    creator_badge_proof: creator_badge
-                        .create_proof_of_all()
-                        .check(self.creator_resource_manager.address()),
-                    minter_badge,
-                    creator_name,
-                    creator_slug,
-                    trophy_name,
-                    trophy_description,
-                    dapp_definition_address: self.dapp_definition_address,
-                }),
-                creator_badge,
-            )
+            let collection = Collection::new(CollectionArg {
+                trophy_resource_manager: self.trophy_resource_manager,
+                thanks_token_resource_manager: self.thanks_token_resource_manager,
+                membership_resource_manager: self.membership_resource_manager,
+                creator_resource_manager: self.creator_resource_manager,
+                repository_owner_access_badge_address: self.repository_owner_access_badge_address,
+                creator_badge_proof: creator_badge
+                    .create_proof_of_all()
+                    .check(self.creator_resource_manager.address()),
+                minter_badge,
+                trophy_name,
+                trophy_description,
+                dapp_definition_address: self.dapp_definition_address,
+                repository: Global::from(Runtime::global_address()),
+                // The creator badge is minted along with the collection, so the creator can not
+                // have a fee rate override yet.
+                fee_rate: None,
+                funding_goal,
+            });
+
+            self.register_collection(collection.address(), creator);
+
+            (collection, creator_badge)
         }
 
         // Mints a new collection owner badge that the user can use to gain ownership of a
         // collection. Ownership badges are free to mint and burn.
         pub fn mint_creator_badge(&mut self, creator_name: String, creator_slug: String) -> Bucket {
-            assert_ne!(
-                creator_name.len(),
-                0,
-                "Creator name must be 1 character or more."
-            );
-
-            assert!(
-                creator_name.len() <= 255,
-                "Creator name must not be over 255 characters"
-            );
+            self.assert_open();
 
-            assert_ne!(
-                creator_slug.len(),
-                0,
-                "Creator slug must be 1 character or more."
-            );
+            validate_creator_name(&creator_name);
 
-            assert!(
-                creator_slug.len() <= 30,
-                "Creator slug must not be over 30 characters"
-            );
+            validate_creator_slug(&creator_slug);
 
             // Get the domain name used from the trophy resource manager.
             let domain: String = self
@@ -472,7 +735,8 @@ mod repository {
                 UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap(),
             );
 
-            self.creator_resource_manager
+            let creator_badge = self
+                .creator_resource_manager
                 .mint_ruid_non_fungible::<Creator>(Creator {
                     name: format!("Creator Badge: {}", creator_name.clone(),),
                     description:
@@ -487,12 +751,295 @@ mod repository {
                         dec!(0),
                         created,
                     )),
-                })
+                });
+
+            let creator = NonFungibleGlobalId::new(
+                self.creator_resource_manager.address(),
+                creator_badge.as_non_fungible().non_fungible_local_id(),
+            );
+
+            self.reserve_creator_slug(creator_slug.clone(), creator.clone());
+
+            Runtime::emit_event(CreatorBadgeMintedEvent {
+                creator,
+                creator_name,
+                creator_slug,
+            });
+
+            creator_badge
+        }
+
+        // update_creator_profile is a method for the holder of a creator badge to update the name,
+        // description and slug of the creator. A changed slug must be available, and the previous
+        // slug of the creator is released.
+        pub fn update_creator_profile(
+            &mut self,
+            creator_badge_proof: Proof,
+            creator_name: String,
+            creator_description: String,
+            creator_slug: String,
+        ) {
+            validate_creator_name(&creator_name);
+
+            assert!(
+                creator_description.len() >= 10,
+                "Creator description must be 10 characters or more."
+            );
+
+            assert!(
+                creator_description.len() <= 255,
+                "Creator description must not be over 255 characters"
+            );
+
+            validate_creator_slug(&creator_slug);
+
+            let checked_creator_badge_proof =
+                creator_badge_proof.check(self.creator_resource_manager.address());
+            let nft_id = checked_creator_badge_proof
+                .as_non_fungible()
+                .non_fungible_local_id();
+            let creator =
+                NonFungibleGlobalId::new(self.creator_resource_manager.address(), nft_id.clone());
+
+            let data = self.get_creator(&creator);
+
+            if data.creator_slug != creator_slug {
+                self.reserve_creator_slug(creator_slug.clone(), creator.clone());
+
+                // The previous slug is only released if it has not been reassigned to another
+                // creator by the admin.
+                let holds_previous_slug = self
+                    .creator_slugs
+                    .get(&data.creator_slug)
+                    .is_some_and(|holder| holder.eq(&creator));
+
+                if holds_previous_slug {
+                    self.creator_slugs.remove(&data.creator_slug);
+                }
+            }
+
+            self.creator_resource_manager.update_non_fungible_data(
+                &nft_id,
+                "name",
+                format!("Creator Badge: {}", creator_name),
+            );
+            self.creator_resource_manager.update_non_fungible_data(
+                &nft_id,
+                "description",
+                creator_description,
+            );
+            self.creator_resource_manager.update_non_fungible_data(
+                &nft_id,
+                "creator_name",
+                creator_name.clone(),
+            );
+            self.creator_resource_manager.update_non_fungible_data(
+                &nft_id,
+                "creator_slug",
+                creator_slug.clone(),
+            );
+
+            Runtime::emit_event(CreatorProfileUpdatedEvent {
+                creator,
+                creator_name,
+                creator_slug,
+            });
+        }
+
+        // merge_creator_badges will take multiple creator badges of the same creator and merge them
+        // into the earliest created badge. The funded amounts are combined, the collections of the
+        // merged badges are handed over to the remaining badge, and the slugs of the merged badges
+        // are released. Trophies and memberships keep the creator badge they were minted for, which
+        // resolves to the remaining badge. The remaining badge keeps its subscription and membership
+        // levels, and only takes over those of a merged badge if it has none.
+        pub fn merge_creator_badges(&mut self, creator_badges: Bucket) -> Bucket {
+            self.assert_open();
+
+            assert_eq!(
+                creator_badges.resource_address(),
+                self.creator_resource_manager.address(),
+                "The given creator badges is not the of the same resource type as managed by the repository."
+            );
+
+            assert!(
+                creator_badges.amount() >= dec!(2),
+                "At least 2 creator badges must be given to be merged."
+            );
+
+            let mut non_fungible_bucket = creator_badges.as_non_fungible();
+            let badges = non_fungible_bucket.non_fungibles::<Creator>();
+
+            // The earliest created badge remains.
+            let mut remaining = badges.first().unwrap();
+            for badge in badges.iter() {
+                let created = parse_created_string(badge.data().created);
+                let remaining_created = parse_created_string(remaining.data().created);
+                if created
+                    .to_instant()
+                    .compare(remaining_created.to_instant(), TimeComparisonOperator::Lt)
+                {
+                    remaining = badge;
+                }
+            }
+
+            let remaining_id = remaining.local_id().clone();
+            let remaining_data = remaining.data();
+            let creator =
+                NonFungibleGlobalId::new(self.creator_resource_manager.address(), remaining_id);
+
+            let mut funded = remaining_data.funded;
+            let mut merged: Vec<NonFungibleGlobalId> = vec![];
+            for badge in badges.iter() {
+                if badge.local_id() == creator.local_id() {
+                    continue;
+                }
+
+                let data = badge.data();
+                let merged_creator = NonFungibleGlobalId::new(
+                    self.creator_resource_manager.address(),
+                    badge.local_id().clone(),
+                );
+
+                funded += data.funded;
+
+                // The slug is only released if it has not been reassigned to another creator by
+                // the admin.
+                let holds_slug = self
+                    .creator_slugs
+                    .get(&data.creator_slug)
+                    .is_some_and(|holder| holder.eq(&merged_creator));
+
+                if holds_slug {
+                    self.creator_slugs.remove(&data.creator_slug);
+                }
+
+                // Hand the collections over to the remaining badge.
+                let collections = self.get_collections(merged_creator.clone());
+                for collection in collections.iter() {
+                    Global::<Collection>::from(*collection).set_creator(creator.clone());
+                    self.add_collection(*collection, creator.clone());
+                }
+                self.collections.remove(&merged_creator);
+
+                if let Some(subscription) = self.subscriptions.remove(&merged_creator) {
+                    Runtime::emit_event(SubscriptionChangedEvent {
+                        creator: merged_creator.clone(),
+                        subscription: None,
+                    });
+
+                    if self.subscriptions.get(&creator).is_none() {
+                        self.subscriptions
+                            .insert(creator.clone(), subscription.clone());
+
+                        Runtime::emit_event(SubscriptionChangedEvent {
+                            creator: creator.clone(),
+                            subscription: Some(subscription),
+                        });
+                    }
+                }
+
+                if let Some(levels) = self.membership_levels.remove(&merged_creator) {
+                    Runtime::emit_event(MembershipLevelsChangedEvent {
+                        creator: merged_creator.clone(),
+                        levels: vec![],
+                    });
+
+                    if self.membership_levels.get(&creator).is_none() {
+                        self.membership_levels
+                            .insert(creator.clone(), levels.clone());
+
+                        Runtime::emit_event(MembershipLevelsChangedEvent {
+                            creator: creator.clone(),
+                            levels,
+                        });
+                    }
+                }
+
+                if let Some(fee_rate) = self.creator_fee_rates.remove(&merged_creator) {
+                    Runtime::emit_event(CreatorFeeRateChangedEvent {
+                        creator: merged_creator.clone(),
+                        fee_rate: None,
+                    });
+
+                    if self.creator_fee_rates.get(&creator).is_none() {
+                        self.creator_fee_rates.insert(creator.clone(), fee_rate);
+
+                        Runtime::emit_event(CreatorFeeRateChangedEvent {
+                            creator: creator.clone(),
+                            fee_rate: Some(fee_rate),
+                        });
+                    }
+                }
+
+                // Hand the perks and their outstanding redemptions over to the remaining badge.
+                let perk_ids = self.get_perks(merged_creator.clone());
+                for perk_id in perk_ids.iter() {
+                    self.perks.get_mut(perk_id).unwrap().creator = creator.clone();
+                }
+
+                let mut perks = self.get_perks(creator.clone());
+                perks.extend(perk_ids);
+                self.creator_perks.insert(creator.clone(), perks);
+                self.creator_perks.remove(&merged_creator);
+
+                let mut outstanding = self.get_outstanding_redemptions(creator.clone());
+                outstanding.extend(self.get_outstanding_redemptions(merged_creator.clone()));
+                self.outstanding_redemptions
+                    .insert(creator.clone(), outstanding);
+                self.outstanding_redemptions.remove(&merged_creator);
+
+                self.creator_aliases
+                    .insert(merged_creator.clone(), creator.clone());
+
+                merged.push(merged_creator);
+            }
+
+            // Get the domain name used from the trophy resource manager.
+            let domain: String = self
+                .trophy_resource_manager
+                .get_metadata("domain")
+                .unwrap()
+                .expect("No domain on NFT repository");
+
+            self.creator_resource_manager.update_non_fungible_data(
+                creator.local_id(),
+                "funded",
+                funded,
+            );
+            self.creator_resource_manager.update_non_fungible_data(
+                creator.local_id(),
+                "key_image_url",
+                UncheckedUrl::of(generate_creator_url(
+                    domain.to_string(),
+                    funded,
+                    remaining_data.created,
+                )),
+            );
+
+            // Burn the merged badges, and return the remaining badge.
+            let merged_badges = non_fungible_bucket.take_non_fungibles(
+                &merged
+                    .iter()
+                    .map(|merged_creator| merged_creator.local_id().clone())
+                    .collect(),
+            );
+            merged_badges.burn();
+
+            Runtime::emit_event(CreatorBadgesMergedEvent {
+                creator,
+                merged,
+                funded,
+            });
+
+            creator_badges
         }
 
         // merge_trophies will take multiple trophies of the same collection id and merge them into
         // one.
         pub fn merge_trophies(&mut self, trophies: Bucket) -> Bucket {
+            self.assert_open();
+            self.assert_not_emergency_paused();
+
             assert_eq!(
                 trophies.resource_address(),
                 self.trophy_resource_manager.address(),
@@ -500,13 +1047,25 @@ mod repository {
             );
 
             let non_fungible_bucket = trophies.as_non_fungible();
+            let merged_ids: Vec<NonFungibleLocalId> = non_fungible_bucket
+                .non_fungible_local_ids()
+                .into_iter()
+                .collect();
             let trophies_list = non_fungible_bucket.non_fungibles::<Trophy>();
             let template = trophies_list.first().unwrap().data();
+            let template_creator = self.resolve_creator(template.creator.clone());
             let mut earliest_created: UtcDateTime =
                 UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap();
 
+            // The merged trophy is named after the newest trophy, as the trophy details of the
+            // collection may have been updated since the earlier trophies were minted.
+            let mut latest_created: Option<UtcDateTime> = None;
+            let mut name = template.name.clone();
+            let mut description = template.description.clone();
+
             let mut donated = dec!(0);
             let mut transactions: Vec<Transaction> = vec![];
+
             for trophy_data in trophies_list.iter() {
                 let data = trophy_data.data();
                 assert_eq!(
@@ -514,30 +1073,13 @@ mod repository {
                     "The given trophies is not the of the same collection id."
                 );
 
-                assert_eq!(
-                    data.info_url, template.info_url,
-                    "The given trophies is not the of the same info url."
-                );
-
-                assert_eq!(
-                    data.name, template.name,
-                    "The given trophies is not the of the same name."
-                );
-
                 assert!(
-                    data.creator.eq(&template.creator),
+                    self.resolve_creator(data.creator.clone())
+                        .eq(&template_creator),
                     "The given trophies does not have the same creator id."
                 );
 
-                assert_eq!(
-                    data.creator_name, template.creator_name,
-                    "The given trophies is not the of the same creator name."
-                );
-
-                assert_eq!(
-                    data.creator_slug, template.creator_slug,
-                    "The given trophies is not the of the same creator slug."
-                );
+                assert!(!data.refunded, "Refunded trophies can not be merged.");
 
                 let trophy_date = parse_created_string(data.created);
 
@@ -548,6 +1090,18 @@ mod repository {
                     earliest_created = trophy_date;
                 }
 
+                let is_newest = latest_created.is_none_or(|latest_created| {
+                    trophy_date
+                        .to_instant()
+                        .compare(latest_created.to_instant(), TimeComparisonOperator::Gte)
+                });
+
+                if is_newest {
+                    latest_created = Some(trophy_date);
+                    name = data.name.clone();
+                    description = data.description.clone();
+                }
+
                 transactions.extend(data.transactions.clone());
                 donated += data.donated;
             }
@@ -573,14 +1127,21 @@ mod repository {
                 .unwrap()
                 .expect("No domain on NFT repository");
 
+            // The merged trophy carries the current name and slug of the creator, and the tier of
+            // the collection reached by the combined donations.
+            let creator = self.get_creator(&template_creator);
+            self.assert_creator_slug(&creator);
+            let tiers = self.get_collection_tiers(&template_creator, &template.collection_id);
+            let tier = resolve_tier(&tiers, donated);
+
             let created = generate_created_string(earliest_created);
             let new_trophy_data = Trophy {
-                name: template.name,
-                description: template.description,
-                creator: template.creator,
-                creator_name: template.creator_name,
-                creator_slug: template.creator_slug,
-                info_url: template.info_url,
+                name,
+                description,
+                creator: template_creator,
+                creator_name: creator.creator_name,
+                creator_slug: creator.creator_slug.clone(),
+                info_url: UncheckedUrl::of(format!("{}/p/{}", domain, creator.creator_slug)),
                 collection_id: template.collection_id.clone(),
                 created: created.clone(),
                 transactions,
@@ -590,19 +1151,182 @@ mod repository {
                     donated,
                     created.clone(),
                     template.collection_id.clone(),
+                    tier.clone(),
                 )),
+                refunded: false,
+                tier,
             };
 
             // Burn the previous trophies.
             trophies.burn();
 
-            self.trophy_resource_manager
-                .mint_ruid_non_fungible(new_trophy_data.clone())
+            let trophy = self
+                .trophy_resource_manager
+                .mint_ruid_non_fungible(new_trophy_data.clone());
+
+            Runtime::emit_event(TrophyMergedEvent {
+                collection_id: template.collection_id,
+                merged_ids,
+                trophy_id: trophy.as_non_fungible().non_fungible_local_id(),
+                donated,
+            });
+
+            trophy
+        }
+
+        // split_trophy will take one trophy and a partition of its transactions, given as indexes
+        // of the transactions, and split it into one trophy per part of the same collection id.
+        pub fn split_trophy(&mut self, trophy: Bucket, partition: Vec<Vec<u32>>) -> Bucket {
+            self.assert_open();
+            self.assert_not_emergency_paused();
+
+            assert_eq!(
+                trophy.resource_address(),
+                self.trophy_resource_manager.address(),
+                "The given trophy is not the of the same resource type as managed by the repository."
+            );
+
+            assert_eq!(
+                trophy.amount(),
+                dec!(1),
+                "Only one trophy can be split at a time."
+            );
+
+            let trophy_id = trophy.as_non_fungible().non_fungible_local_id();
+            let data: Trophy = self
+                .trophy_resource_manager
+                .get_non_fungible_data(&trophy_id);
+
+            assert!(!data.refunded, "Refunded trophies can not be split.");
+
+            assert!(
+                partition.len() >= 2,
+                "A trophy must be split into 2 trophies or more."
+            );
+
+            // Every transaction must be in exactly one part of the partition.
+            let mut used = vec![false; data.transactions.len()];
+            for part in partition.iter() {
+                assert!(
+                    !part.is_empty(),
+                    "Every part of the partition must have 1 transaction or more."
+                );
+
+                for index in part.iter() {
+                    let index = *index as usize;
+                    assert!(
+                        index < data.transactions.len(),
+                        "The partition refers to a transaction that does not exist."
+                    );
+
+                    assert!(
+                        !used[index],
+                        "The partition refers to a transaction more than once."
+                    );
+
+                    used[index] = true;
+                }
+            }
+
+            assert!(
+                used.iter().all(|used| *used),
+                "The partition must include every transaction of the trophy."
+            );
+
+            // Get the domain name used from the trophy resource manager.
+            let domain: String = self
+                .trophy_resource_manager
+                .get_metadata("domain")
+                .unwrap()
+                .expect("No domain on NFT repository");
+
+            // The split trophies carries the current name and slug of the creator, and get their
+            // tier from the collection. Only donations in the counted resource of the collection
+            // counts towards their donated amount.
+            let creator_id = self.resolve_creator(data.creator.clone());
+            let creator = self.get_creator(&creator_id);
+            self.assert_creator_slug(&creator);
+            let tiers = self.get_collection_tiers(&creator_id, &data.collection_id);
+            let counted_resource = self
+                .get_collection(&creator_id, &data.collection_id)
+                .expect("The collection of the given trophy is not found.")
+                .get_counted_resource();
+
+            // Burn the previous trophy.
+            trophy.burn();
+
+            let mut trophies = Bucket::new(self.trophy_resource_manager.address());
+            let mut split_ids: Vec<NonFungibleLocalId> = vec![];
+            for part in partition.iter() {
+                let mut transactions: Vec<Transaction> = part
+                    .iter()
+                    .map(|index| data.transactions[*index as usize].clone())
+                    .collect();
+
+                // Sort the transactions by created date.
+                transactions.sort_by(|a, b| {
+                    let a_date = parse_created_string(a.created.clone());
+                    let b_date = parse_created_string(b.created.clone());
+                    if a_date
+                        .to_instant()
+                        .compare(b_date.to_instant(), TimeComparisonOperator::Lt)
+                    {
+                        return Ordering::Less;
+                    }
+
+                    Ordering::Greater
+                });
+
+                let created = transactions.first().unwrap().created.clone();
+                let donated = transactions.iter().fold(dec!(0), |total, transaction| {
+                    total + counted_amount(transaction, counted_resource)
+                });
+                let tier = resolve_tier(&tiers, donated);
+
+                let new_trophy_data = Trophy {
+                    name: data.name.clone(),
+                    description: data.description.clone(),
+                    creator: creator_id.clone(),
+                    creator_name: creator.creator_name.clone(),
+                    creator_slug: creator.creator_slug.clone(),
+                    info_url: UncheckedUrl::of(format!("{}/p/{}", domain, creator.creator_slug)),
+                    collection_id: data.collection_id.clone(),
+                    created: created.clone(),
+                    transactions,
+                    donated,
+                    key_image_url: UncheckedUrl::of(generate_trophy_url(
+                        domain.to_string(),
+                        donated,
+                        created,
+                        data.collection_id.clone(),
+                        tier.clone(),
+                    )),
+                    refunded: false,
+                    tier,
+                };
+
+                let new_trophy = self
+                    .trophy_resource_manager
+                    .mint_ruid_non_fungible(new_trophy_data);
+                split_ids.push(new_trophy.as_non_fungible().non_fungible_local_id());
+                trophies.put(new_trophy);
+            }
+
+            Runtime::emit_event(TrophySplitEvent {
+                collection_id: data.collection_id,
+                trophy_id,
+                split_ids,
+            });
+
+            trophies
         }
 
         // merge_memberships will take multiple memberships of the same creator and merge them into
         // one.
         pub fn merge_memberships(&mut self, memberships: Bucket) -> Bucket {
+            self.assert_open();
+            self.assert_not_emergency_paused();
+
             assert_eq!(
                 memberships.resource_address(),
                 self.membership_resource_manager.address(),
@@ -610,40 +1334,28 @@ mod repository {
             );
 
             let non_fungible_bucket = memberships.as_non_fungible();
+            let merged_ids: Vec<NonFungibleLocalId> = non_fungible_bucket
+                .non_fungible_local_ids()
+                .into_iter()
+                .collect();
             let membership_list = non_fungible_bucket.non_fungibles::<Membership>();
             let template = membership_list.first().unwrap().data();
+            let template_creator = self.resolve_creator(template.creator.clone());
             let mut earliest_created: UtcDateTime =
                 UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap();
 
             let mut donated = dec!(0);
+            let mut perpetual = false;
+            let mut latest_expires_at: Option<Instant> = None;
             let mut transactions: Vec<Transaction> = vec![];
             for membership_data in membership_list.iter() {
                 let data = membership_data.data();
-                assert_eq!(
-                    data.info_url, template.info_url,
-                    "The given memberships is not the of the same created date."
-                );
-
-                assert_eq!(
-                    data.name, template.name,
-                    "The given memberships is not the of the same name."
-                );
-
                 assert!(
-                    data.creator.eq(&template.creator),
+                    self.resolve_creator(data.creator.clone())
+                        .eq(&template_creator),
                     "The given memberships does not have the same creator id."
                 );
 
-                assert_eq!(
-                    data.creator_name, template.creator_name,
-                    "The given memberships is not the of the same creator name."
-                );
-
-                assert_eq!(
-                    data.creator_slug, template.creator_slug,
-                    "The given memberships is not the of the same creator slug."
-                );
-
                 let membership_date = parse_created_string(data.created);
 
                 if membership_date
@@ -653,10 +1365,25 @@ mod repository {
                     earliest_created = membership_date;
                 }
 
+                // The merged membership expires with the latest expiring membership, and is
+                // perpetual if any of the memberships is perpetual.
+                match data.expires_at {
+                    Some(membership_expires_at) => {
+                        if latest_expires_at.is_none_or(|latest| {
+                            membership_expires_at.compare(latest, TimeComparisonOperator::Gt)
+                        }) {
+                            latest_expires_at = Some(membership_expires_at);
+                        }
+                    }
+                    None => perpetual = true,
+                }
+
                 transactions.extend(data.transactions.clone());
                 donated += data.donated;
             }
 
+            let expires_at = if perpetual { None } else { latest_expires_at };
+
             // Sort the transactions by created date.
             transactions.sort_by(|a, b| {
                 let a_date = parse_created_string(a.created.clone());
@@ -678,47 +1405,609 @@ mod repository {
                 .unwrap()
                 .expect("No domain on NFT repository");
 
+            // The merged membership carries the current name and slug of the creator.
+            let creator = self.get_creator(&template_creator);
+            self.assert_creator_slug(&creator);
+
             let created = generate_created_string(earliest_created);
+            let level = resolve_tier(
+                &self.get_membership_levels(template_creator.clone()),
+                donated,
+            );
             let new_membership_data = Membership {
-                name: template.name,
-                description: template.description,
-                creator: template.creator.clone(),
-                creator_name: template.creator_name.clone(),
-                creator_slug: template.creator_slug.clone(),
+                name: format!("Membership: {}", creator.creator_name),
+                description: format!("Digital emblem celebrating {}'s crowdfunding journey. It evolves with cumulative donations. It's a symbol of encouragement, encapsulating the artist-backer bond in the digital age.", creator.creator_name).to_string(),
+                creator: template_creator.clone(),
+                creator_name: creator.creator_name.clone(),
+                creator_slug: creator.creator_slug.clone(),
                 created: created.clone(),
-                info_url: template.info_url,
+                info_url: UncheckedUrl::of(format!("{}/p/{}", domain, creator.creator_slug)),
                 transactions,
                 donated,
                 key_image_url: UncheckedUrl::of(generate_membership_url(
                     domain.to_string(),
                     donated,
                     created.clone(),
-                    template.creator_slug.clone(),
+                    creator.creator_slug.clone(),
+                    level.clone(),
                 )),
+                expires_at,
+                level,
             };
 
             // Burn the previous trophies.
             memberships.burn();
 
-            self.membership_resource_manager
-                .mint_ruid_non_fungible(new_membership_data.clone())
+            let membership = self
+                .membership_resource_manager
+                .mint_ruid_non_fungible(new_membership_data.clone());
+
+            Runtime::emit_event(MembershipMergedEvent {
+                creator: template_creator,
+                merged_ids,
+                membership_id: membership.as_non_fungible().non_fungible_local_id(),
+                donated,
+            });
+
+            membership
         }
 
         // redeem_thanks_token is a method for the backers to redeem thanks tokens.
         pub fn redeem_thanks_token(&mut self, thanks_token: Bucket) {
+            let amount = thanks_token.amount();
             self.thanks_token_resource_manager.burn(thanks_token);
+
+            Runtime::emit_event(ThanksRedeemedEvent { amount });
+        }
+
+        // add_perk is a method for creators to list a perk that backers can redeem with thanks
+        // tokens, with a limited stock. Returns the id of the perk.
+        pub fn add_perk(
+            &mut self,
+            creator_badge_proof: Proof,
+            name: String,
+            description: String,
+            price: Decimal,
+            stock: u64,
+        ) -> u64 {
+            self.assert_open();
+
+            validate_perk(&name, &description, price);
+
+            let creator = self.check_creator_badge_proof(creator_badge_proof);
+
+            let perk_id = self.next_perk_id;
+            self.next_perk_id += 1;
+
+            self.perks.insert(
+                perk_id,
+                Perk {
+                    creator: creator.clone(),
+                    name,
+                    description,
+                    price,
+                    stock,
+                    redeemed: 0,
+                },
+            );
+
+            let mut perks = self.get_perks(creator.clone());
+            perks.push(perk_id);
+            self.creator_perks.insert(creator.clone(), perks);
+
+            Runtime::emit_event(PerkAddedEvent {
+                creator,
+                perk_id,
+                price,
+                stock,
+            });
+
+            perk_id
+        }
+
+        // set_perk_stock is a method for creators to restock a perk, or to stop it from being
+        // redeemed by setting the stock to 0.
+        pub fn set_perk_stock(&mut self, creator_badge_proof: Proof, perk_id: u64, stock: u64) {
+            let creator = self.check_creator_badge_proof(creator_badge_proof);
+
+            let mut perk = self
+                .perks
+                .get_mut(&perk_id)
+                .expect("The perk does not exist.");
+
+            assert!(
+                perk.creator.eq(&creator),
+                "The perk was not listed by the given creator badge."
+            );
+
+            perk.stock = stock;
+
+            Runtime::emit_event(PerkStockChangedEvent {
+                creator,
+                perk_id,
+                stock,
+            });
+        }
+
+        // get_perk returns the perk with the given id.
+        pub fn get_perk(&self, perk_id: u64) -> Option<Perk> {
+            self.perks.get(&perk_id).map(|perk| perk.clone())
+        }
+
+        // get_perks returns the ids of the perks listed by the creator.
+        pub fn get_perks(&self, creator: NonFungibleGlobalId) -> Vec<u64> {
+            self.creator_perks
+                .get(&creator)
+                .map(|perks| perks.clone())
+                .unwrap_or_default()
+        }
+
+        // redeem_perk is a method for backers to redeem a perk by burning thanks tokens of the
+        // price of the perk. Returns a receipt referencing the perk, and the remaining thanks
+        // tokens.
+        pub fn redeem_perk(&mut self, perk_id: u64, mut thanks_token: Bucket) -> (Bucket, Bucket) {
+            self.assert_open();
+
+            assert_eq!(
+                thanks_token.resource_address(),
+                self.thanks_token_resource_manager.address(),
+                "The given thanks tokens is not the of the same resource type as managed by the repository."
+            );
+
+            let perk = {
+                let mut perk = self
+                    .perks
+                    .get_mut(&perk_id)
+                    .expect("The perk does not exist.");
+
+                assert!(perk.stock > 0, "The perk is out of stock.");
+
+                assert!(
+                    thanks_token.amount() >= perk.price,
+                    "The perk costs {} thanks tokens.",
+                    perk.price
+                );
+
+                perk.stock -= 1;
+                perk.redeemed += 1;
+                perk.clone()
+            };
+
+            self.thanks_token_resource_manager
+                .burn(thanks_token.take(perk.price));
+
+            let receipt = self
+                .redemption_receipt_resource_manager
+                .mint_ruid_non_fungible(RedemptionReceipt {
+                    name: format!("Receipt: {}", perk.name),
+                    description: perk.description,
+                    perk_id,
+                    creator: perk.creator.clone(),
+                    price: perk.price,
+                    created: generate_created_string(
+                        UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes())
+                            .unwrap(),
+                    ),
+                    fulfilled: false,
+                });
+            let receipt_id = receipt.as_non_fungible().non_fungible_local_id();
+
+            let mut outstanding = self.get_outstanding_redemptions(perk.creator.clone());
+            outstanding.push(receipt_id.clone());
+            self.outstanding_redemptions
+                .insert(perk.creator.clone(), outstanding);
+
+            Runtime::emit_event(PerkRedeemedEvent {
+                creator: perk.creator,
+                perk_id,
+                receipt_id,
+                price: perk.price,
+            });
+
+            (receipt, thanks_token)
+        }
+
+        // fulfil_redemption is a method for creators to mark a redemption of one of their perks as
+        // fulfilled, when the perk has been delivered to the backer.
+        pub fn fulfil_redemption(
+            &mut self,
+            creator_badge_proof: Proof,
+            receipt_id: NonFungibleLocalId,
+        ) {
+            let creator = self.check_creator_badge_proof(creator_badge_proof);
+
+            let receipt: RedemptionReceipt = self
+                .redemption_receipt_resource_manager
+                .get_non_fungible_data(&receipt_id);
+
+            // Receipts of perks handed over by a merged badge still carry the merged badge.
+            assert!(
+                self.resolve_creator(receipt.creator.clone()).eq(&creator),
+                "The redemption is not of a perk listed by the given creator badge."
+            );
+
+            assert!(
+                !receipt.fulfilled,
+                "The redemption has already been fulfilled."
+            );
+
+            self.redemption_receipt_resource_manager
+                .update_non_fungible_data(&receipt_id, "fulfilled", true);
+
+            let mut outstanding = self.get_outstanding_redemptions(creator.clone());
+            outstanding.retain(|outstanding_id| *outstanding_id != receipt_id);
+            self.outstanding_redemptions
+                .insert(creator.clone(), outstanding);
+
+            Runtime::emit_event(RedemptionFulfilledEvent {
+                creator,
+                receipt_id,
+            });
+        }
+
+        // get_outstanding_redemptions returns the receipts of redemptions of perks listed by the
+        // creator, that has not been fulfilled.
+        pub fn get_outstanding_redemptions(
+            &self,
+            creator: NonFungibleGlobalId,
+        ) -> Vec<NonFungibleLocalId> {
+            self.outstanding_redemptions
+                .get(&creator)
+                .map(|outstanding| outstanding.clone())
+                .unwrap_or_default()
         }
 
         // close_repository is a method for the repository admin to close the repository
         // permanently. This will prevent any further collections to be made from the repository,
-        // and will prevent any further usage of this repository.
+        // and will prevent any further usage of this repository. Existing collections check the
+        // repository on every donation, which halts all minting across the platform.
         pub fn close_repository(&mut self) {
-            if self.closed.is_some() {
-                panic!("This repository is permanently closed.");
+            self.assert_open();
+
+            let closed =
+                UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap();
+            self.closed = Some(closed);
+
+            Runtime::emit_event(RepositoryClosedEvent { closed });
+        }
+
+        // set_fee_rate is a method for the repository admin to change the fee rate taken from
+        // donations in all collections that don't override it.
+        pub fn set_fee_rate(&mut self, fee_rate: Decimal) {
+            validate_fee_rate(fee_rate);
+            self.fee_rate = fee_rate;
+
+            Runtime::emit_event(FeeRateChangedEvent {
+                collection_id: None,
+                fee_rate: Some(fee_rate),
+            });
+        }
+
+        // get_closed returns the date the repository was closed, or None if it is open.
+        pub fn get_closed(&self) -> Option<UtcDateTime> {
+            self.closed
+        }
+
+        // emergency_pause is a method for the emergency role to halt donations and minting in all
+        // collections of the repository, until it is unpaused.
+        pub fn emergency_pause(&mut self) {
+            self.assert_not_emergency_paused();
+
+            let paused =
+                UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap();
+            self.emergency_paused = Some(paused);
+
+            Runtime::emit_event(EmergencyPausedEvent { paused });
+        }
+
+        // emergency_unpause is a method for the emergency role to resume donations and minting in
+        // all collections of the repository.
+        pub fn emergency_unpause(&mut self) {
+            assert!(
+                self.emergency_paused.is_some(),
+                "This repository is not paused in an emergency."
+            );
+
+            let unpaused =
+                UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap();
+            self.emergency_paused = None;
+
+            Runtime::emit_event(EmergencyUnpausedEvent { unpaused });
+        }
+
+        // get_emergency_paused returns the date the repository was paused in an emergency, or None
+        // if it is not paused.
+        pub fn get_emergency_paused(&self) -> Option<UtcDateTime> {
+            self.emergency_paused
+        }
+
+        // get_fee_rate returns the fee rate taken from donations in all collections that don't
+        // override it.
+        pub fn get_fee_rate(&self) -> Decimal {
+            self.fee_rate
+        }
+
+        // set_creator_fee_rate is a method for the repository admin to override the fee rate of the
+        // collections a creator sets up from now on, such as 0% for charity creators. Setting it to
+        // None makes new collections use the repository fee rate. Existing collections are changed
+        // through their set_fee_rate method.
+        pub fn set_creator_fee_rate(
+            &mut self,
+            creator: NonFungibleGlobalId,
+            fee_rate: Option<Decimal>,
+        ) {
+            assert_eq!(
+                creator.resource_address(),
+                self.creator_resource_manager.address(),
+                "The given creator is not the of the same resource type as managed by the repository."
+            );
+
+            let creator = self.resolve_creator(creator);
+
+            match fee_rate {
+                Some(fee_rate) => {
+                    validate_fee_rate(fee_rate);
+                    self.creator_fee_rates.insert(creator.clone(), fee_rate);
+                }
+                None => {
+                    self.creator_fee_rates.remove(&creator);
+                }
+            }
+
+            Runtime::emit_event(CreatorFeeRateChangedEvent { creator, fee_rate });
+        }
+
+        // get_creator_fee_rate returns the fee rate override for the collections of the creator,
+        // or None if the repository fee rate is used.
+        pub fn get_creator_fee_rate(&self, creator: NonFungibleGlobalId) -> Option<Decimal> {
+            self.creator_fee_rates
+                .get(&self.resolve_creator(creator))
+                .map(|fee_rate| *fee_rate)
+        }
+
+        // set_donation_limits is a method for the repository admin to change the minimum and
+        // maximum donation of a resource in all collections that don't override them. Setting it
+        // to None stops collections without an override from accepting the resource.
+        pub fn set_donation_limits(
+            &mut self,
+            resource_address: ResourceAddress,
+            donation_limits: Option<DonationLimits>,
+        ) {
+            match &donation_limits {
+                Some(donation_limits) => {
+                    validate_donation_limits(donation_limits);
+                    self.donation_limits
+                        .insert(resource_address, donation_limits.clone());
+                }
+                None => {
+                    self.donation_limits.remove(&resource_address);
+                }
+            }
+
+            Runtime::emit_event(DonationLimitsChangedEvent {
+                collection_id: None,
+                resource_address,
+                donation_limits,
+            });
+        }
+
+        // get_donation_limits returns the donation limits of a resource in all collections that
+        // don't override them, or None if the resource has no limits.
+        pub fn get_donation_limits(
+            &self,
+            resource_address: ResourceAddress,
+        ) -> Option<DonationLimits> {
+            self.donation_limits
+                .get(&resource_address)
+                .map(|donation_limits| donation_limits.clone())
+        }
+
+        // get_collections returns the addresses of the collections owned by a creator badge.
+        pub fn get_collections(&self, creator: NonFungibleGlobalId) -> Vec<ComponentAddress> {
+            self.collections
+                .get(&creator)
+                .map(|collections| collections.clone())
+                .unwrap_or_default()
+        }
+
+        // get_collection_creator returns the creator badge that owns a collection, or None if the
+        // collection was not instantiated by this repository.
+        pub fn get_collection_creator(
+            &self,
+            collection: ComponentAddress,
+        ) -> Option<NonFungibleGlobalId> {
+            self.collection_creators
+                .get(&collection)
+                .map(|creator| creator.clone())
+        }
+
+        // is_collection returns true if the given component is a collection instantiated by this
+        // repository.
+        pub fn is_collection(&self, collection: ComponentAddress) -> bool {
+            self.collection_creators.get(&collection).is_some()
+        }
+
+        // release_creator_slug is a method for the repository admin to release a creator slug,
+        // making it available to be reserved by another creator.
+        pub fn release_creator_slug(&mut self, creator_slug: String) {
+            let holder = self
+                .creator_slugs
+                .remove(&creator_slug)
+                .expect("Creator slug is not reserved.");
+
+            // The holder keeps its badge, but no longer carries the released slug.
+            self.creator_resource_manager.update_non_fungible_data(
+                holder.local_id(),
+                "creator_slug",
+                String::new(),
+            );
+
+            Runtime::emit_event(CreatorSlugChangedEvent {
+                creator_slug,
+                creator: None,
+            });
+        }
+
+        // reassign_creator_slug is a method for the repository admin to hand over a creator slug to
+        // another creator badge.
+        pub fn reassign_creator_slug(
+            &mut self,
+            creator_slug: String,
+            creator: NonFungibleGlobalId,
+        ) {
+            validate_creator_slug(&creator_slug);
+
+            assert_eq!(
+                creator.resource_address(),
+                self.creator_resource_manager.address(),
+                "The given creator is not the of the same resource type as managed by the repository."
+            );
+
+            let creator = self.resolve_creator(creator);
+
+            // The previous holder of the slug is left without one on its badge.
+            if let Some(holder) = self.creator_slugs.get(&creator_slug).map(|h| h.clone()) {
+                self.creator_resource_manager.update_non_fungible_data(
+                    holder.local_id(),
+                    "creator_slug",
+                    String::new(),
+                );
+            }
+
+            // The slug the new holder had before is freed, unless it was already handed over to
+            // another creator.
+            let data = self.get_creator(&creator);
+            let holds_previous_slug = data.creator_slug != creator_slug
+                && self
+                    .creator_slugs
+                    .get(&data.creator_slug)
+                    .is_some_and(|holder| holder.eq(&creator));
+
+            if holds_previous_slug {
+                self.creator_slugs.remove(&data.creator_slug);
+
+                Runtime::emit_event(CreatorSlugChangedEvent {
+                    creator_slug: data.creator_slug,
+                    creator: None,
+                });
             }
 
-            self.closed =
-                Some(UtcDateTime::from_instant(&Clock::current_time_rounded_to_minutes()).unwrap());
+            self.creator_slugs
+                .insert(creator_slug.clone(), creator.clone());
+
+            self.creator_resource_manager.update_non_fungible_data(
+                creator.local_id(),
+                "creator_slug",
+                creator_slug.clone(),
+            );
+
+            Runtime::emit_event(CreatorSlugChangedEvent {
+                creator_slug,
+                creator: Some(creator),
+            });
+        }
+
+        // set_subscription is a method for creators to offer memberships as subscriptions, where a
+        // donation buys days of membership. Setting it to None turns the subscription mode off,
+        // memberships minted without a subscription never expire.
+        pub fn set_subscription(
+            &mut self,
+            creator_badge_proof: Proof,
+            subscription: Option<Subscription>,
+        ) {
+            if let Some(subscription) = &subscription {
+                validate_subscription(subscription);
+            }
+
+            let creator = self.check_creator_badge_proof(creator_badge_proof);
+
+            match &subscription {
+                Some(subscription) => self
+                    .subscriptions
+                    .insert(creator.clone(), subscription.clone()),
+                None => {
+                    self.subscriptions.remove(&creator);
+                }
+            }
+
+            Runtime::emit_event(SubscriptionChangedEvent {
+                creator,
+                subscription,
+            });
+        }
+
+        // get_subscription returns the membership subscription offered by the creator.
+        pub fn get_subscription(&self, creator: NonFungibleGlobalId) -> Option<Subscription> {
+            self.subscriptions
+                .get(&creator)
+                .map(|subscription| subscription.clone())
+        }
+
+        // is_membership_active returns true if the membership has not expired. Memberships without
+        // an expiry are always active, and memberships expire at the minute of their expiry.
+        pub fn is_membership_active(&self, membership_id: NonFungibleLocalId) -> bool {
+            let data: Membership = self
+                .membership_resource_manager
+                .get_non_fungible_data(&membership_id);
+
+            data.expires_at.is_none_or(|expires_at| {
+                Clock::current_time_is_strictly_before(expires_at, TimePrecision::Minute)
+            })
+        }
+
+        // set_membership_levels is a method for creators to name the levels of their memberships,
+        // such as Supporter, Patron and Champion. Memberships get their level when minted, updated
+        // with a new donation, or merged.
+        pub fn set_membership_levels(&mut self, creator_badge_proof: Proof, levels: Vec<Tier>) {
+            validate_tiers(&levels);
+
+            let creator = self.check_creator_badge_proof(creator_badge_proof);
+
+            self.membership_levels
+                .insert(creator.clone(), levels.clone());
+
+            Runtime::emit_event(MembershipLevelsChangedEvent { creator, levels });
+        }
+
+        // get_membership_levels returns the membership levels of the creator.
+        pub fn get_membership_levels(&self, creator: NonFungibleGlobalId) -> Vec<Tier> {
+            self.membership_levels
+                .get(&creator)
+                .map_or(vec![], |levels| levels.clone())
+        }
+
+        // get_creator_slug_owner returns the creator badge that holds a creator slug, or None if
+        // the slug is not reserved.
+        pub fn get_creator_slug_owner(&self, creator_slug: String) -> Option<NonFungibleGlobalId> {
+            self.creator_slugs
+                .get(&creator_slug)
+                .map(|creator| creator.clone())
+        }
+
+        // resolve_creator returns the creator badge that a creator badge was merged into, or the
+        // given badge if it has not been merged.
+        pub fn resolve_creator(&self, creator: NonFungibleGlobalId) -> NonFungibleGlobalId {
+            let mut creator = creator;
+            while let Some(alias) = self
+                .creator_aliases
+                .get(&creator)
+                .map(|alias| alias.clone())
+            {
+                creator = alias;
+            }
+
+            creator
+        }
+
+        // approve_milestone is a method for the repository admin to approve a milestone declared by
+        // the owner of a collection, unlocking its share of the donations.
+        pub fn approve_milestone(&mut self, collection: ComponentAddress, index: u32) {
+            assert!(
+                self.is_collection(collection),
+                "Collection is not instantiated by this repository."
+            );
+
+            Global::<Collection>::from(collection).approve_milestone(index);
         }
     }
 }
@@ -1,3 +1,6 @@
+use crate::data::{
+    DonationLimits, FundingGoal, Matching, Split, Subscription, Tier, Transaction, Vesting,
+};
 use scrypto::prelude::*;
 
 // function to generate the url for the image
@@ -6,11 +9,17 @@ pub fn generate_trophy_url(
     donated: Decimal,
     created: String,
     collection_id: String,
+    tier: Option<String>,
 ) -> String {
-    format!(
+    let url = format!(
         "{}/nft/collection/{}?donated={}&created={}",
         base_path, collection_id, donated, created
-    )
+    );
+
+    match tier {
+        Some(tier) => format!("{}&tier={}", url, tier),
+        None => url,
+    }
 }
 
 // function to generate the url for the image
@@ -19,11 +28,17 @@ pub fn generate_membership_url(
     donated: Decimal,
     created: String,
     creator_slug: String,
+    level: Option<String>,
 ) -> String {
-    format!(
+    let url = format!(
         "{}/nft/membership/{}?donated={}&created={}",
         base_path, creator_slug, donated, created
-    )
+    );
+
+    match level {
+        Some(level) => format!("{}&level={}", url, level),
+        None => url,
+    }
 }
 
 // function to generate the url for the image
@@ -34,6 +49,392 @@ pub fn generate_creator_url(base_path: String, donated: Decimal, created: String
     )
 }
 
+// validate_fee_rate asserts that the fee rate is within the bounds of 0% and 100%.
+pub fn validate_fee_rate(fee_rate: Decimal) {
+    assert!(
+        fee_rate >= dec!(0),
+        "Fee rate must not be lower than 0 (0%)."
+    );
+
+    assert!(
+        fee_rate <= dec!(1),
+        "Fee rate must not be higher than 1 (100%)."
+    );
+}
+
+// validate_funding_goal asserts that the funding goal has a positive amount and a deadline in the
+// future. All-or-nothing campaigns must have a deadline.
+pub fn validate_funding_goal(funding_goal: &FundingGoal) {
+    assert!(
+        funding_goal.amount > dec!(0),
+        "Funding goal amount must be higher than 0."
+    );
+
+    if funding_goal.all_or_nothing {
+        assert!(
+            funding_goal.deadline.is_some(),
+            "All-or-nothing campaigns must have a deadline."
+        );
+    }
+
+    if let Some(deadline) = funding_goal.deadline {
+        assert!(
+            Clock::current_time_is_strictly_before(deadline, TimePrecision::Minute),
+            "Funding goal deadline must be in the future."
+        );
+    }
+}
+
+// validate_trophy_details asserts that a trophy name is between 3 and 32 characters, and that a
+// trophy description is between 10 and 255 characters.
+pub fn validate_trophy_details(trophy_name: &str, trophy_description: &str) {
+    assert!(
+        trophy_name.len() >= 3,
+        "Trophy name must be 3 characters or more."
+    );
+
+    assert!(
+        trophy_name.len() <= 32,
+        "Trophy name must not be over 32 characters"
+    );
+
+    assert!(
+        trophy_description.len() >= 10,
+        "Trophy description must be 10 characters or more."
+    );
+
+    assert!(
+        trophy_description.len() <= 255,
+        "Trophy description must not be over 255 characters"
+    );
+}
+
+// validate_perk asserts that a perk name is between 3 and 32 characters, that a perk description
+// is between 10 and 255 characters, and that the price is positive.
+pub fn validate_perk(name: &str, description: &str, price: Decimal) {
+    assert!(name.len() >= 3, "Perk name must be 3 characters or more.");
+
+    assert!(
+        name.len() <= 32,
+        "Perk name must not be over 32 characters."
+    );
+
+    assert!(
+        description.len() >= 10,
+        "Perk description must be 10 characters or more."
+    );
+
+    assert!(
+        description.len() <= 255,
+        "Perk description must not be over 255 characters."
+    );
+
+    assert!(price > dec!(0), "Perk price must be higher than 0.");
+}
+
+// validate_creator_name asserts that a creator name is between 1 and 255 characters.
+pub fn validate_creator_name(creator_name: &str) {
+    assert_ne!(
+        creator_name.len(),
+        0,
+        "Creator name must be 1 character or more."
+    );
+
+    assert!(
+        creator_name.len() <= 255,
+        "Creator name must not be over 255 characters"
+    );
+}
+
+// validate_creator_slug asserts that a creator slug is between 1 and 30 characters, and only
+// contains lowercase letters, digits and dashes.
+pub fn validate_creator_slug(creator_slug: &str) {
+    assert_ne!(
+        creator_slug.len(),
+        0,
+        "Creator slug must be 1 character or more."
+    );
+
+    assert!(
+        creator_slug.len() <= 30,
+        "Creator slug must not be over 30 characters"
+    );
+
+    assert!(
+        creator_slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'),
+        "Creator slug must only contain lowercase letters, digits and dashes."
+    );
+}
+
+// validate_message asserts that a backer message is not empty and not over 255 characters.
+pub fn validate_message(message: &str) {
+    assert_ne!(message.len(), 0, "Message must be 1 character or more.");
+
+    assert!(
+        message.len() <= 255,
+        "Message must not be over 255 characters"
+    );
+}
+
+// validate_donation_limits asserts that the minimum donation is higher than 0, so that empty
+// donations can not mint trophies, and that the maximum donation is not lower than the minimum
+// donation.
+pub fn validate_donation_limits(donation_limits: &DonationLimits) {
+    assert!(
+        donation_limits.minimum > dec!(0),
+        "Minimum donation must be higher than 0."
+    );
+
+    if let Some(maximum) = donation_limits.maximum {
+        assert!(
+            maximum >= donation_limits.minimum,
+            "Maximum donation must not be lower than the minimum donation."
+        );
+    }
+}
+
+// validate_tiers asserts that every tier has a name that can be used in the image url, and that
+// the tiers are ordered by ascending thresholds.
+pub fn validate_tiers(tiers: &[Tier]) {
+    let mut previous_threshold: Option<Decimal> = None;
+    for tier in tiers.iter() {
+        assert!(
+            !tier.name.is_empty(),
+            "Tier name must be 1 character or more."
+        );
+
+        assert!(
+            tier.name.len() <= 32,
+            "Tier name must not be over 32 characters"
+        );
+
+        assert!(
+            tier.name.chars().all(|c| c.is_ascii_alphanumeric()),
+            "Tier name must only contain letters and digits."
+        );
+
+        assert!(
+            tier.threshold > dec!(0),
+            "Tier threshold must be higher than 0."
+        );
+
+        if let Some(previous_threshold) = previous_threshold {
+            assert!(
+                tier.threshold > previous_threshold,
+                "Tiers must be ordered by ascending thresholds."
+            );
+        }
+
+        previous_threshold = Some(tier.threshold);
+    }
+}
+
+// validate_splits asserts that every payee has a positive share in basis points, that a payee is
+// only listed once, and that the shares sum up to 100%. No splits turns the revenue split off.
+pub fn validate_splits(splits: &[Split]) {
+    if splits.is_empty() {
+        return;
+    }
+
+    let mut total: u32 = 0;
+    for (index, split) in splits.iter().enumerate() {
+        assert!(split.share > 0, "Split share must be higher than 0.");
+
+        assert!(
+            !splits[..index]
+                .iter()
+                .any(|previous| previous.payee == split.payee),
+            "Split payee must only be listed once."
+        );
+
+        total += split.share as u32;
+    }
+
+    assert_eq!(
+        total, 10000,
+        "Split shares must sum up to 10000 basis points."
+    );
+}
+
+// split_amounts returns the share of every payee of the given amount, the last payee receives what
+// remains after the shares of the other payees.
+pub fn split_amounts(splits: &[Split], amount: Decimal) -> Vec<Decimal> {
+    let mut remaining = amount;
+    splits
+        .iter()
+        .enumerate()
+        .map(|(index, split)| {
+            if index == splits.len() - 1 {
+                return remaining;
+            }
+
+            let share = amount * Decimal::from(split.share) / dec!(10000);
+            remaining -= share;
+            share
+        })
+        .collect()
+}
+
+// add_amount adds an amount of a resource to a list of amounts per resource.
+pub fn add_amount(
+    amounts: &mut Vec<(ResourceAddress, Decimal)>,
+    resource_address: ResourceAddress,
+    amount: Decimal,
+) {
+    match amounts
+        .iter_mut()
+        .find(|(existing, _)| *existing == resource_address)
+    {
+        Some((_, existing_amount)) => *existing_amount += amount,
+        None => amounts.push((resource_address, amount)),
+    }
+}
+
+// validate_subscription asserts that a subscription has a positive price, and buys at least one
+// day of membership.
+pub fn validate_subscription(subscription: &Subscription) {
+    assert!(
+        subscription.price > dec!(0),
+        "Subscription price must be higher than 0."
+    );
+
+    assert!(
+        subscription.days > 0,
+        "Subscription must buy at least 1 day of membership."
+    );
+}
+
+// extend_expiry returns the expiry of a membership after the given donation. A donation of at
+// least the subscription price buys the subscription days, counted from the current expiry if the
+// membership is still active, otherwise from now. Memberships without an expiry are perpetual, and
+// stay perpetual.
+pub fn extend_expiry(
+    expires_at: Option<Instant>,
+    subscription: &Subscription,
+    transaction: &Transaction,
+) -> Option<Instant> {
+    let expires_at = expires_at?;
+
+    if transaction.resource_address != subscription.resource_address
+        || transaction.amount < subscription.price
+    {
+        return Some(expires_at);
+    }
+
+    let now = Clock::current_time_rounded_to_minutes();
+    let start = if expires_at.compare(now, TimeComparisonOperator::Gt) {
+        expires_at
+    } else {
+        now
+    };
+
+    Some(
+        start
+            .add_days(subscription.days as i64)
+            .expect("The expiry of the membership is out of range."),
+    )
+}
+
+// validate_matching asserts that a matching has a positive ratio and cap.
+pub fn validate_matching(matching: &Matching) {
+    assert!(
+        matching.ratio > dec!(0),
+        "Matching ratio must be higher than 0."
+    );
+
+    assert!(
+        matching.cap > dec!(0),
+        "Matching cap must be higher than 0."
+    );
+}
+
+// validate_vesting asserts that a linear vesting does not start in the past and lasts at least one
+// day, or that the milestones are described, not yet approved, and that their shares sum up to
+// 10000 basis points.
+pub fn validate_vesting(vesting: &Vesting) {
+    match vesting {
+        Vesting::Linear { start, days } => {
+            assert!(
+                Clock::current_time_is_at_or_before(*start, TimePrecision::Minute),
+                "Vesting must not start in the past."
+            );
+
+            assert!(*days > 0, "Vesting must last at least 1 day.");
+        }
+        Vesting::Milestones(milestones) => {
+            let mut total: u32 = 0;
+            for milestone in milestones.iter() {
+                assert!(
+                    !milestone.description.is_empty(),
+                    "Milestone description must not be empty."
+                );
+
+                assert!(
+                    milestone.share > 0,
+                    "Milestone share must be higher than 0."
+                );
+
+                assert!(
+                    !milestone.approved,
+                    "Milestone must not be approved when declared."
+                );
+
+                total += milestone.share as u32;
+            }
+
+            assert_eq!(
+                total, 10000,
+                "Milestone shares must sum up to 10000 basis points."
+            );
+        }
+    }
+}
+
+// unlocked_share returns the share of the donations that a vesting has unlocked, from 0 to 1. A
+// linear vesting unlocks the donations evenly over its days, and milestones unlock their share when
+// approved.
+pub fn unlocked_share(vesting: &Vesting) -> Decimal {
+    match vesting {
+        Vesting::Linear { start, days } => {
+            let elapsed = Clock::current_time_rounded_to_minutes().seconds_since_unix_epoch
+                - start.seconds_since_unix_epoch;
+            let duration = *days as i64 * 86400;
+
+            (Decimal::from(elapsed.max(0)) / Decimal::from(duration)).min(dec!(1))
+        }
+        Vesting::Milestones(milestones) => {
+            let approved: u32 = milestones
+                .iter()
+                .filter(|milestone| milestone.approved)
+                .map(|milestone| milestone.share as u32)
+                .sum();
+
+            Decimal::from(approved) / dec!(10000)
+        }
+    }
+}
+
+// counted_amount returns the amount of a transaction that counts towards the donated amount, which
+// is only counted for donations in the counted resource of the collection.
+pub fn counted_amount(transaction: &Transaction, counted_resource: ResourceAddress) -> Decimal {
+    if transaction.resource_address == counted_resource {
+        transaction.amount
+    } else {
+        dec!(0)
+    }
+}
+
+// resolve_tier returns the name of the highest tier that the donated amount qualifies for.
+pub fn resolve_tier(tiers: &[Tier], donated: Decimal) -> Option<String> {
+    tiers
+        .iter()
+        .rev()
+        .find(|tier| donated >= tier.threshold)
+        .map(|tier| tier.name.clone())
+}
+
 // function to generate the created string with a date format
 pub fn generate_created_string(time: UtcDateTime) -> String {
     let mut month = time.month().to_string();
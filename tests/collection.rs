@@ -1,8 +1,14 @@
 #[path = "./common.rs"]
 mod common;
-use common::{execute_manifest, mint_creator_badge, new_account, new_runner};
-
-use backeum_blueprint::data::{Membership, Trophy};
+use common::{
+    execute_manifest, mint_creator_badge, mint_creator_badge_with_slug, new_account, new_runner,
+};
+
+use backeum_blueprint::data::{
+    DonationLimits, FundingGoal, FundingProgress, Matching, Membership, Payee, Split, Subscription,
+    Tier, Trophy, Vesting,
+};
+use backeum_blueprint::events::{DonationEvent, TrophyMintedEvent};
 use scrypto::prelude::*;
 use transaction::builder::ManifestBuilder;
 
@@ -39,6 +45,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -62,7 +69,7 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
             .take_from_worktop(XRD, dec!(150), "donation_amount")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount"),)
+                (lookup.bucket("donation_amount"), None::<String>)
             })
             .assert_worktop_contains(base.trophy_resource_address, dec!(1))
             .assert_worktop_contains(base.membership_resource_address, dec!(1))
@@ -218,6 +225,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -241,7 +249,7 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
             .take_from_worktop(XRD, dec!(150), "donation_amount")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount"),)
+                (lookup.bucket("donation_amount"), None::<String>)
             })
             .assert_worktop_contains(base.trophy_resource_address, dec!(1))
             .assert_worktop_contains(base.membership_resource_address, dec!(1))
@@ -296,6 +304,7 @@ mod tests {
                     (
                         lookup.bucket("donation_amount"),
                         lookup.proof("membership_proof"),
+                        None::<String>,
                     )
                 },
             )
@@ -459,6 +468,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -502,7 +512,7 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
             .take_from_worktop(XRD, dec!(150), "donation_amount")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount"),)
+                (lookup.bucket("donation_amount"), None::<String>)
             })
             .assert_worktop_contains(base.trophy_resource_address, dec!(1))
             .assert_worktop_contains(base.thanks_token_resource_address, dec!(150))
@@ -563,6 +573,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -586,7 +597,7 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
             .take_from_worktop(XRD, dec!(150), "donation_amount")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount"),)
+                (lookup.bucket("donation_amount"), None::<String>)
             })
             .assert_worktop_contains(base.trophy_resource_address, dec!(1))
             .assert_worktop_contains(base.membership_resource_address, dec!(1))
@@ -648,7 +659,11 @@ mod tests {
                 "proof",
             )
             .call_method_with_name_lookup(collection_component, "donate_update", |lookup| {
-                (lookup.bucket("donation_amount"), lookup.proof("proof"))
+                (
+                    lookup.bucket("donation_amount"),
+                    lookup.proof("proof"),
+                    None::<String>,
+                )
             })
             .assert_worktop_contains(base.thanks_token_resource_address, dec!(150))
             .assert_worktop_contains(base.membership_resource_address, dec!(1))
@@ -726,6 +741,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -749,7 +765,7 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
             .take_from_worktop(XRD, dec!(150), "donation_amount")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount"),)
+                (lookup.bucket("donation_amount"), None::<String>)
             })
             .assert_worktop_contains(base.trophy_resource_address, dec!(1))
             .assert_worktop_contains(base.membership_resource_address, dec!(1))
@@ -830,6 +846,7 @@ mod tests {
                         lookup.bucket("donation_amount"),
                         lookup.proof("trophy_proof"),
                         lookup.proof("membership_proof"),
+                        None::<String>,
                     )
                 },
             )
@@ -928,6 +945,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -951,7 +969,7 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
             .take_from_worktop(XRD, dec!(150), "donation_amount")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount"),)
+                (lookup.bucket("donation_amount"), None::<String>)
             })
             .assert_worktop_contains(base.trophy_resource_address, dec!(1))
             .assert_worktop_contains(base.thanks_token_resource_address, dec!(150))
@@ -1015,7 +1033,7 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
             .take_from_worktop(XRD, dec!(100), "donation_amount")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount"),)
+                (lookup.bucket("donation_amount"), None::<String>)
             })
             .assert_worktop_contains(base.trophy_resource_address, dec!(1))
             .assert_worktop_contains(base.thanks_token_resource_address, dec!(100))
@@ -1064,6 +1082,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -1129,6 +1148,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -1216,6 +1236,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -1239,7 +1260,7 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
             .take_from_worktop(XRD, dec!(150), "donation_amount")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount"),)
+                (lookup.bucket("donation_amount"), None::<String>)
             })
             .assert_worktop_contains(base.trophy_resource_address, dec!(1))
             .assert_worktop_contains(base.thanks_token_resource_address, dec!(150))
@@ -1317,6 +1338,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -1373,7 +1395,12 @@ mod tests {
         let creator_badge_account_2 = new_account(&mut base.test_runner);
         let creator_badge_badge_id_2: NonFungibleGlobalId;
         {
-            creator_badge_badge_id_2 = mint_creator_badge(&mut base, &creator_badge_account_2);
+            creator_badge_badge_id_2 = mint_creator_badge_with_slug(
+                &mut base,
+                &creator_badge_account_2,
+                "Kansuler",
+                "kansuler-2",
+            );
         }
 
         // Create two collection components
@@ -1391,6 +1418,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -1431,4 +1459,3513 @@ mod tests {
 
         receipt.expect_commit_failure();
     }
+
+    #[test]
+    fn funding_goal_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create a collection component with a funding goal of 200 XRD
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        Some(FundingGoal {
+                            resource_address: XRD,
+                            amount: dec!(200),
+                            deadline: None,
+                            all_or_nothing: false,
+                        }),
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "funding_goal_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donate and mint trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
+            .take_from_worktop(XRD, dec!(150), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "funding_goal_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let manifest = ManifestBuilder::new().call_method(
+            collection_component,
+            "get_funding_progress",
+            manifest_args!(),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "funding_goal_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        let progress: FundingProgress = receipt.expect_commit_success().output(0);
+        assert_eq!(progress.raised, dec!(150));
+        assert_eq!(progress.goal, Some(dec!(200)));
+        assert_eq!(progress.percentage, Some(dec!(75)));
+        assert!(!progress.goal_reached);
+
+        // Donate again, reaching the funding goal
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "funding_goal_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let manifest = ManifestBuilder::new().call_method(
+            collection_component,
+            "get_funding_progress",
+            manifest_args!(),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "funding_goal_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        let progress: FundingProgress = receipt.expect_commit_success().output(0);
+        assert_eq!(progress.raised, dec!(250));
+        assert_eq!(progress.percentage, Some(dec!(125)));
+        assert!(progress.goal_reached);
+    }
+
+    #[test]
+    fn funding_goal_failure_invalid_amount() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component with a funding goal of 0 XRD
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        Some(FundingGoal {
+                            resource_address: XRD,
+                            amount: dec!(0),
+                            deadline: None,
+                            all_or_nothing: false,
+                        }),
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "funding_goal_failure_invalid_amount_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn funding_goal_success_counted_resource() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        let stablecoin = base.test_runner.create_fungible_resource(
+            dec!(1000),
+            DIVISIBILITY_MAXIMUM,
+            creator_badge_account.wallet_address,
+        );
+
+        // Create a collection component with a funding goal in the stablecoin
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        Some(FundingGoal {
+                            resource_address: stablecoin,
+                            amount: dec!(500),
+                            deadline: None,
+                            all_or_nothing: false,
+                        }),
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "funding_goal_success_counted_resource_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donations are counted in the resource of the funding goal
+        let manifest = ManifestBuilder::new().call_method(
+            collection_component,
+            "get_counted_resource",
+            manifest_args!(),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "funding_goal_success_counted_resource_2",
+            vec![],
+            true,
+        );
+
+        let counted_resource: ResourceAddress = receipt.expect_commit_success().output(0);
+        assert_eq!(counted_resource, stablecoin);
+    }
+
+    #[test]
+    fn refund_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create an all-or-nothing collection component that ends in one day
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        Some(FundingGoal {
+                            resource_address: XRD,
+                            amount: dec!(1000),
+                            deadline: Some(Instant::new(1699093188 + 86400)),
+                            all_or_nothing: true,
+                        }),
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "refund_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donate and mint trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
+            .take_from_worktop(XRD, dec!(150), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "refund_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // The creator can not withdraw donations before the campaign has succeeded
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(collection_component, "withdraw_donations", manifest_args!())
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "refund_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Move past the deadline of the campaign
+        base.test_runner
+            .advance_to_round_at_timestamp(Round::of(50), 1699093188267 + 2 * 86400 * 1000);
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id: NonFungibleLocalId;
+        {
+            let mut trophies = base
+                .test_runner
+                .inspect_non_fungible_vault(trophy_vault[0])
+                .unwrap()
+                .1;
+
+            trophy_id = trophies.next().unwrap().clone();
+        }
+
+        // Refund the donation by presenting the trophy and returning the thanks tokens
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(
+                donation_account.wallet_address,
+                base.thanks_token_resource_address,
+                dec!(150),
+            )
+            .take_from_worktop(base.thanks_token_resource_address, dec!(150), "thanks")
+            .create_proof_from_account_of_non_fungible(
+                donation_account.wallet_address,
+                NonFungibleGlobalId::new(base.trophy_resource_address, trophy_id.clone()),
+            )
+            .create_proof_from_auth_zone_of_non_fungibles(
+                base.trophy_resource_address,
+                vec![trophy_id.clone()],
+                "proof",
+            )
+            .call_method_with_name_lookup(collection_component, "refund", |lookup| {
+                (lookup.proof("proof"), lookup.bucket("thanks"))
+            })
+            .assert_worktop_contains(XRD, dec!(150))
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "refund_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(donation_account.wallet_address, XRD),
+            dec!(10000)
+        );
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id);
+
+        assert!(trophy_data.refunded);
+
+        // The repository owner can withdraw the fees of a failed campaign, which are empty
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(collection_component, "withdraw_fees", manifest_args!())
+            .deposit_batch(base.owner_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "refund_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+    }
+
+    #[test]
+    fn refund_success_partial_thanks() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create an all-or-nothing collection component that ends in one day
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        Some(FundingGoal {
+                            resource_address: XRD,
+                            amount: dec!(1000),
+                            deadline: Some(Instant::new(1699093188 + 86400)),
+                            all_or_nothing: true,
+                        }),
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "refund_success_partial_thanks_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donate and mint trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
+            .take_from_worktop(XRD, dec!(150), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "refund_success_partial_thanks_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // The creator can not withdraw donations before the campaign has succeeded
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(collection_component, "withdraw_donations", manifest_args!())
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "refund_success_partial_thanks_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Move past the deadline of the campaign
+        base.test_runner
+            .advance_to_round_at_timestamp(Round::of(50), 1699093188267 + 2 * 86400 * 1000);
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id: NonFungibleLocalId;
+        {
+            let mut trophies = base
+                .test_runner
+                .inspect_non_fungible_vault(trophy_vault[0])
+                .unwrap()
+                .1;
+
+            trophy_id = trophies.next().unwrap().clone();
+        }
+
+        // Refund the donation by presenting the trophy and returning the thanks tokens that has
+        // not been spent, the spent thanks tokens are withheld from the refund
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(
+                donation_account.wallet_address,
+                base.thanks_token_resource_address,
+                dec!(100),
+            )
+            .take_from_worktop(base.thanks_token_resource_address, dec!(100), "thanks")
+            .create_proof_from_account_of_non_fungible(
+                donation_account.wallet_address,
+                NonFungibleGlobalId::new(base.trophy_resource_address, trophy_id.clone()),
+            )
+            .create_proof_from_auth_zone_of_non_fungibles(
+                base.trophy_resource_address,
+                vec![trophy_id.clone()],
+                "proof",
+            )
+            .call_method_with_name_lookup(collection_component, "refund", |lookup| {
+                (lookup.proof("proof"), lookup.bucket("thanks"))
+            })
+            .assert_worktop_contains(XRD, dec!(100))
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "refund_success_partial_thanks_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(donation_account.wallet_address, XRD),
+            dec!(9950)
+        );
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id);
+
+        assert!(trophy_data.refunded);
+
+        // The repository owner withdraws the withheld part of the refund as fees
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(collection_component, "withdraw_fees", manifest_args!())
+            .deposit_batch(base.owner_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "refund_success_partial_thanks_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(base.owner_account.wallet_address, XRD),
+            dec!(10050)
+        );
+    }
+
+    #[test]
+    fn donate_mint_accepted_resource_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account, holding a stablecoin
+        let donation_account = new_account(&mut base.test_runner);
+        let stablecoin = base.test_runner.create_fungible_resource(
+            dec!(1000),
+            DIVISIBILITY_MAXIMUM,
+            donation_account.wallet_address,
+        );
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_accepted_resource_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donating a resource that is not accepted fails
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, stablecoin, dec!(100))
+            .take_from_worktop(stablecoin, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_accepted_resource_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Accept the stablecoin as donation
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "add_accepted_resource",
+                manifest_args!(stablecoin),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_accepted_resource_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Donating an accepted resource without donation limits fails
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, stablecoin, dec!(100))
+            .take_from_worktop(stablecoin, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_accepted_resource_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Set the donation limits of the stablecoin
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(
+                collection_component,
+                "set_donation_limits",
+                manifest_args!(
+                    stablecoin,
+                    Some(DonationLimits {
+                        minimum: dec!(10),
+                        maximum: None,
+                    })
+                ),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_accepted_resource_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, stablecoin, dec!(100))
+            .take_from_worktop(stablecoin, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .assert_worktop_contains(base.trophy_resource_address, dec!(1))
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_accepted_resource_success_6",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(donation_account.wallet_address, stablecoin),
+            dec!(900)
+        );
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id: NonFungibleLocalId;
+        {
+            let mut trophies = base
+                .test_runner
+                .inspect_non_fungible_vault(trophy_vault[0])
+                .unwrap()
+                .1;
+
+            trophy_id = trophies.next().unwrap().clone();
+        }
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id);
+
+        assert_eq!(trophy_data.transactions.len(), 1);
+        assert_eq!(trophy_data.transactions[0].amount, dec!(100));
+        assert_eq!(trophy_data.transactions[0].resource_address, stablecoin);
+
+        // Donations in other resources than the counted resource are not counted
+        assert_eq!(trophy_data.donated, dec!(0));
+        assert_eq!(
+            base.test_runner.get_component_balance(
+                donation_account.wallet_address,
+                base.thanks_token_resource_address
+            ),
+            dec!(0)
+        );
+    }
+
+    #[test]
+    fn tiers_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "tiers_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Set the donation tiers of the collection
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(
+                collection_component,
+                "set_tiers",
+                manifest_args!(vec![
+                    Tier {
+                        name: "Bronze".to_owned(),
+                        threshold: dec!(10),
+                    },
+                    Tier {
+                        name: "Silver".to_owned(),
+                        threshold: dec!(100),
+                    },
+                    Tier {
+                        name: "Gold".to_owned(),
+                        threshold: dec!(1000),
+                    },
+                ]),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "tiers_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Donate and mint trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
+            .take_from_worktop(XRD, dec!(150), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "tiers_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id: NonFungibleLocalId;
+        {
+            let mut trophies = base
+                .test_runner
+                .inspect_non_fungible_vault(trophy_vault[0])
+                .unwrap()
+                .1;
+
+            trophy_id = trophies.next().unwrap().clone();
+        }
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id.clone());
+
+        assert_eq!(trophy_data.tier, Some("Silver".to_owned()));
+        assert_eq!(
+            trophy_data.key_image_url,
+            UncheckedUrl::of(format!(
+                "https://localhost:8080/nft/collection/{}?donated=150&created=2023-11-04&tier=Silver",
+                trophy_data.collection_id
+            ))
+        );
+
+        // Donate more to reach the next tier
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(900))
+            .take_from_worktop(XRD, dec!(900), "donation_amount")
+            .create_proof_from_account_of_non_fungible(
+                donation_account.wallet_address,
+                NonFungibleGlobalId::new(base.trophy_resource_address, trophy_id.clone()),
+            )
+            .create_proof_from_auth_zone_of_non_fungibles(
+                base.trophy_resource_address,
+                vec![trophy_id.clone()],
+                "proof",
+            )
+            .call_method_with_name_lookup(collection_component, "donate_update", |lookup| {
+                (
+                    lookup.bucket("donation_amount"),
+                    lookup.proof("proof"),
+                    None::<String>,
+                )
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "tiers_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id);
+
+        assert_eq!(trophy_data.tier, Some("Gold".to_owned()));
+        assert_eq!(
+            trophy_data.key_image_url,
+            UncheckedUrl::of(format!(
+                "https://localhost:8080/nft/collection/{}?donated=1050&created=2023-11-04&tier=Gold",
+                trophy_data.collection_id
+            ))
+        );
+    }
+
+    #[test]
+    fn tiers_failure_unordered() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "tiers_failure_unordered_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(
+                collection_component,
+                "set_tiers",
+                manifest_args!(vec![
+                    Tier {
+                        name: "Gold".to_owned(),
+                        threshold: dec!(1000),
+                    },
+                    Tier {
+                        name: "Bronze".to_owned(),
+                        threshold: dec!(10),
+                    },
+                ]),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "tiers_failure_unordered_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn donation_limits_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donation_limits_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donating dust below the repository minimum fails
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(0.5))
+            .take_from_worktop(XRD, dec!(0.5), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donation_limits_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Override the donation limits of the collection
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(
+                collection_component,
+                "set_donation_limits",
+                manifest_args!(
+                    XRD,
+                    Some(DonationLimits {
+                        minimum: dec!(50),
+                        maximum: None,
+                    })
+                ),
+            )
+            .call_method(
+                collection_component,
+                "get_donation_limits",
+                manifest_args!(XRD),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donation_limits_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let donation_limits: Option<DonationLimits> = receipt.expect_commit_success().output(2);
+        let donation_limits = donation_limits.unwrap();
+        assert_eq!(donation_limits.minimum, dec!(50));
+        assert_eq!(donation_limits.maximum, None);
+
+        // Donating below the collection minimum fails
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(20))
+            .take_from_worktop(XRD, dec!(20), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donation_limits_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Donating above the collection minimum succeeds
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(50))
+            .take_from_worktop(XRD, dec!(50), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donation_limits_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+    }
+
+    #[test]
+    fn donate_mint_message_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_message_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donate with a message to the creator
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
+            .take_from_worktop(XRD, dec!(150), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (
+                    lookup.bucket("donation_amount"),
+                    Some("Keep up the great work!"),
+                )
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_message_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        let event = receipt
+            .expect_commit_success()
+            .application_events
+            .iter()
+            .find_map(|(_, data)| scrypto_decode::<DonationEvent>(data).ok())
+            .unwrap();
+
+        assert_eq!(event.amount, dec!(150));
+        assert_eq!(event.message, Some("Keep up the great work!".to_owned()));
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id: NonFungibleLocalId;
+        {
+            let mut trophies = base
+                .test_runner
+                .inspect_non_fungible_vault(trophy_vault[0])
+                .unwrap()
+                .1;
+
+            trophy_id = trophies.next().unwrap().clone();
+        }
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id);
+
+        assert_eq!(
+            trophy_data.transactions[0].message,
+            Some("Keep up the great work!".to_owned())
+        );
+    }
+
+    #[test]
+    fn donate_mint_message_failure_length() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_message_failure_length_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donate with a message over 255 characters
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(150))
+            .take_from_worktop(XRD, dec!(150), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), Some("a".repeat(256)))
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_message_failure_length_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn donate_mint_events_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_events_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donate and mint trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_mint_events_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        let result = receipt.expect_commit_success();
+
+        let find_event = |name: &str| -> Vec<u8> {
+            result
+                .application_events
+                .iter()
+                .find(|(event_type_identifier, _)| {
+                    base.test_runner.event_name(event_type_identifier) == name
+                })
+                .map(|(_, data)| data.clone())
+                .unwrap()
+        };
+
+        let donation_event: DonationEvent = scrypto_decode(&find_event("DonationEvent")).unwrap();
+        let trophy_minted_event: TrophyMintedEvent =
+            scrypto_decode(&find_event("TrophyMintedEvent")).unwrap();
+
+        let collection_id = AddressBech32Encoder::new(&NetworkDefinition::simulator())
+            .encode(&collection_component.to_vec())
+            .unwrap();
+
+        assert_eq!(donation_event.collection_id, collection_id);
+        assert_eq!(donation_event.resource_address, XRD);
+        assert_eq!(donation_event.amount, dec!(100));
+        assert_eq!(donation_event.fee, dec!(4));
+        assert_eq!(
+            donation_event.trophy_id,
+            Some(trophy_minted_event.trophy_id)
+        );
+        assert_eq!(trophy_minted_event.collection_id, collection_id);
+        assert_eq!(trophy_minted_event.donated, dec!(100));
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id = base
+            .test_runner
+            .inspect_non_fungible_vault(trophy_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        assert_eq!(donation_event.trophy_id, trophy_id);
+    }
+
+    #[test]
+    fn update_trophy_details_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "update_trophy_details_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Donate and mint a trophy with the initial details
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "update_trophy_details_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id = base
+            .test_runner
+            .inspect_non_fungible_vault(trophy_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        // Update the trophy details, and re-stamp the existing trophy
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(
+                collection_component,
+                "update_trophy_details",
+                manifest_args!("Fixed trophy name", "Kansulers fixed trophy"),
+            )
+            .call_method(
+                collection_component,
+                "restamp_trophies",
+                manifest_args!(vec![trophy_id.clone()]),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "update_trophy_details_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id.clone());
+
+        assert_eq!(trophy_data.name, "Fixed trophy name");
+        assert_eq!(trophy_data.description, "Kansulers fixed trophy");
+
+        // Donate and mint a trophy with the updated details
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "update_trophy_details_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let new_trophy_id = base
+            .test_runner
+            .inspect_non_fungible_vault(trophy_vault[0])
+            .unwrap()
+            .1
+            .find(|id| *id != trophy_id)
+            .unwrap();
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, new_trophy_id);
+
+        assert_eq!(trophy_data.name, "Fixed trophy name");
+        assert_eq!(trophy_data.description, "Kansulers fixed trophy");
+    }
+
+    #[test]
+    fn update_trophy_details_failure_invalid_name() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "update_trophy_details_failure_invalid_name_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(
+                collection_component,
+                "update_trophy_details",
+                manifest_args!("Tr", "Kansulers fixed trophy"),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "update_trophy_details_failure_invalid_name_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn pause_collection_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Pause the collection as the collection owner
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(collection_component, "pause_collection", manifest_args!());
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let manifest = ManifestBuilder::new().call_method(
+            collection_component,
+            "get_paused",
+            manifest_args!(),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_success_3",
+            vec![],
+            true,
+        );
+
+        let paused: Option<UtcDateTime> = receipt.expect_commit_success().output(0);
+        assert!(paused.is_some());
+
+        // Donations are not accepted while paused
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Resume the collection as the repository owner
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(collection_component, "resume_collection", manifest_args!());
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Donations are accepted again
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_success_6",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Pause the collection as the repository owner
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "admin_pause_collection",
+                manifest_args!(),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_success_7",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // The collection owner can not resume a pause by the repository owner
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(collection_component, "resume_collection", manifest_args!());
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_success_8",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(collection_component, "resume_collection", manifest_args!());
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_success_9",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+    }
+
+    #[test]
+    fn pause_collection_failure_auth() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_failure_auth_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create another account without the creator badge
+        let other_account = new_account(&mut base.test_runner);
+
+        let manifest = ManifestBuilder::new().call_method(
+            collection_component,
+            "pause_collection",
+            manifest_args!(),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_failure_auth_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &other_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // The collection owner can not pause the collection as the repository owner
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(
+                collection_component,
+                "admin_pause_collection",
+                manifest_args!(),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "pause_collection_failure_auth_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn renew_membership_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Offer memberships as a subscription of 30 days for 50 XRD
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "set_subscription",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        Some(Subscription {
+                            resource_address: XRD,
+                            price: dec!(50),
+                            days: 30,
+                        }),
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Donate and mint a membership that expires in 30 days
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let membership_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.membership_resource_address,
+        );
+
+        let membership_id = base
+            .test_runner
+            .inspect_non_fungible_vault(membership_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        // Genesis rounded down to minutes
+        let created = 1699093140;
+
+        let membership_data: Membership = base
+            .test_runner
+            .get_non_fungible_data(base.membership_resource_address, membership_id.clone());
+
+        assert_eq!(
+            membership_data.expires_at,
+            Some(Instant::new(created + 30 * 86400))
+        );
+
+        // Renew the membership for another 30 days
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(50))
+            .take_from_worktop(XRD, dec!(50), "donation_amount")
+            .create_proof_from_account_of_non_fungible(
+                donation_account.wallet_address,
+                NonFungibleGlobalId::new(base.membership_resource_address, membership_id.clone()),
+            )
+            .create_proof_from_auth_zone_of_non_fungibles(
+                base.membership_resource_address,
+                vec![membership_id.clone()],
+                "membership_proof",
+            )
+            .call_method_with_name_lookup(collection_component, "renew_membership", |lookup| {
+                (
+                    lookup.bucket("donation_amount"),
+                    lookup.proof("membership_proof"),
+                    None::<String>,
+                )
+            })
+            .assert_worktop_contains(base.thanks_token_resource_address, dec!(50))
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        let result = receipt.expect_commit_success();
+
+        assert!(result
+            .application_events
+            .iter()
+            .any(|(event_type_identifier, _)| {
+                base.test_runner.event_name(event_type_identifier) == "DonationEvent"
+            }));
+
+        let membership_data: Membership = base
+            .test_runner
+            .get_non_fungible_data(base.membership_resource_address, membership_id.clone());
+
+        assert_eq!(
+            membership_data.expires_at,
+            Some(Instant::new(created + 60 * 86400))
+        );
+
+        // The membership is active until it expires
+        let manifest = ManifestBuilder::new().call_method(
+            base.repository_component,
+            "is_membership_active",
+            manifest_args!(membership_id.clone()),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_5",
+            vec![],
+            true,
+        );
+
+        let active: bool = receipt.expect_commit_success().output(0);
+        assert!(active);
+
+        base.test_runner
+            .advance_to_round_at_timestamp(Round::of(50), (created + 61 * 86400) * 1000);
+
+        let manifest = ManifestBuilder::new().call_method(
+            base.repository_component,
+            "is_membership_active",
+            manifest_args!(membership_id),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_6",
+            vec![],
+            true,
+        );
+
+        let active: bool = receipt.expect_commit_success().output(0);
+        assert!(!active);
+
+        // A donation below the subscription price mints a membership that has already expired
+        let other_donation_account = new_account(&mut base.test_runner);
+
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(other_donation_account.wallet_address, XRD, dec!(10))
+            .take_from_worktop(XRD, dec!(10), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(other_donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_7",
+            vec![NonFungibleGlobalId::from_public_key(
+                &other_donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let membership_vault = base.test_runner.get_component_vaults(
+            other_donation_account.wallet_address,
+            base.membership_resource_address,
+        );
+
+        let membership_id = base
+            .test_runner
+            .inspect_non_fungible_vault(membership_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        let membership_data: Membership = base
+            .test_runner
+            .get_non_fungible_data(base.membership_resource_address, membership_id.clone());
+
+        assert_eq!(
+            membership_data.expires_at,
+            Some(Instant::new(created + 61 * 86400))
+        );
+
+        let manifest = ManifestBuilder::new().call_method(
+            base.repository_component,
+            "is_membership_active",
+            manifest_args!(membership_id),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_8",
+            vec![],
+            true,
+        );
+
+        let active: bool = receipt.expect_commit_success().output(0);
+        assert!(!active);
+    }
+
+    #[test]
+    fn renew_membership_success_perpetual() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_perpetual_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Donate and mint a membership before the creator offers subscriptions, which does not
+        // expire
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_perpetual_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let membership_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.membership_resource_address,
+        );
+
+        let membership_id = base
+            .test_runner
+            .inspect_non_fungible_vault(membership_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        // Offer memberships as a subscription of 30 days for 50 XRD
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "set_subscription",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        Some(Subscription {
+                            resource_address: XRD,
+                            price: dec!(50),
+                            days: 30,
+                        }),
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_perpetual_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Renewing the membership keeps it perpetual
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(50))
+            .take_from_worktop(XRD, dec!(50), "donation_amount")
+            .create_proof_from_account_of_non_fungible(
+                donation_account.wallet_address,
+                NonFungibleGlobalId::new(base.membership_resource_address, membership_id.clone()),
+            )
+            .create_proof_from_auth_zone_of_non_fungibles(
+                base.membership_resource_address,
+                vec![membership_id.clone()],
+                "membership_proof",
+            )
+            .call_method_with_name_lookup(collection_component, "renew_membership", |lookup| {
+                (
+                    lookup.bucket("donation_amount"),
+                    lookup.proof("membership_proof"),
+                    None::<String>,
+                )
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_perpetual_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let membership_data: Membership = base
+            .test_runner
+            .get_non_fungible_data(base.membership_resource_address, membership_id.clone());
+
+        assert_eq!(membership_data.expires_at, None);
+
+        // A membership without an expiry is always active
+        let manifest = ManifestBuilder::new().call_method(
+            base.repository_component,
+            "is_membership_active",
+            manifest_args!(membership_id),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "renew_membership_success_perpetual_5",
+            vec![],
+            true,
+        );
+
+        let active: bool = receipt.expect_commit_success().output(0);
+        assert!(active);
+    }
+
+    #[test]
+    fn splits_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "splits_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create a band member account, that is paid to the account
+        let member_account = new_account(&mut base.test_runner);
+        let member_payee = Payee::Account(member_account.wallet_address);
+        let creator_payee = Payee::Badge(creator_badge_badge_id.clone());
+
+        // Split the donations 60/40 between the creator and the band member
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "set_splits",
+                manifest_args!(vec![
+                    Split {
+                        payee: creator_payee.clone(),
+                        share: 6000u16
+                    },
+                    Split {
+                        payee: member_payee.clone(),
+                        share: 4000u16
+                    }
+                ]),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "splits_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Donate 100 XRD, 96 XRD remains after the fee
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "splits_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // The owner can no longer withdraw all donations
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(collection_component, "withdraw_donations", manifest_args!());
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "splits_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // The share of the band member is deposited into their account, by anyone
+        let manifest = ManifestBuilder::new().call_method(
+            collection_component,
+            "claim_share",
+            manifest_args!(member_payee),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "splits_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(member_account.wallet_address, XRD),
+            dec!(10038.4)
+        );
+
+        // The creator claims their share
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "claim_share",
+                manifest_args!(creator_payee.clone()),
+            )
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "splits_success_6",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(creator_badge_account.wallet_address, XRD),
+            dec!(10057.6)
+        );
+
+        // The band member can not claim the share of the creator
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                collection_component,
+                "claim_share",
+                manifest_args!(creator_payee),
+            )
+            .deposit_batch(member_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "splits_success_7",
+            vec![NonFungibleGlobalId::from_public_key(
+                &member_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Someone who is not a payee can not claim a share
+        let donation_payee = Payee::Badge(NonFungibleGlobalId::from_public_key(
+            &donation_account.public_key,
+        ));
+
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                collection_component,
+                "claim_share",
+                manifest_args!(donation_payee),
+            )
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "splits_success_8",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn auto_payout_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "auto_payout_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Pay out the donations to the creator account
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "set_auto_payout",
+                manifest_args!(Some(creator_badge_account.wallet_address)),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "auto_payout_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Donate 100 XRD, 96 XRD is paid out after the fee
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "auto_payout_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(creator_badge_account.wallet_address, XRD),
+            dec!(10096)
+        );
+
+        // Splits can not be set while the donations are paid out
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "set_splits",
+                manifest_args!(vec![Split {
+                    payee: Payee::Badge(creator_badge_badge_id),
+                    share: 10000u16
+                }]),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "auto_payout_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn vesting_linear_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "vesting_linear_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Vest the donations linearly over 10 days, starting now
+        let start = 1699093140;
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "set_vesting",
+                manifest_args!(Vesting::Linear {
+                    start: Instant::new(start),
+                    days: 10u32
+                }),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "vesting_linear_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Donate 100 XRD, 96 XRD is locked after the fee
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address)
+            .call_method(
+                collection_component,
+                "get_vesting_balance",
+                manifest_args!(XRD),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "vesting_linear_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        let balance: (Decimal, Decimal) = receipt.expect_commit_success().output(4);
+        assert_eq!(balance, (dec!(96), dec!(0)));
+
+        // Half of the donations are unlocked after 5 days
+        base.test_runner
+            .advance_to_round_at_timestamp(Round::of(50), (start + 5 * 86400) * 1000);
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(collection_component, "withdraw_donations", manifest_args!())
+            .deposit_batch(creator_badge_account.wallet_address)
+            .call_method(
+                collection_component,
+                "get_vesting_balance",
+                manifest_args!(XRD),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "vesting_linear_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let balance: (Decimal, Decimal) = receipt.expect_commit_success().output(3);
+        assert_eq!(balance, (dec!(48), dec!(0)));
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(creator_badge_account.wallet_address, XRD),
+            dec!(10048)
+        );
+
+        // Vesting can not be changed once set
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(
+                collection_component,
+                "set_vesting",
+                manifest_args!(Vesting::Linear {
+                    start: Instant::new(start + 5 * 86400),
+                    days: 1u32
+                }),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "vesting_linear_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn matching_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "matching_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Match every donation up to 50 XRD
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "set_matching",
+                manifest_args!(Some(Matching {
+                    ratio: dec!(1),
+                    cap: dec!(50)
+                })),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "matching_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // A sponsor funds the matching pool with 100 XRD, recorded by the signature of the
+        // sponsor
+        let sponsor_account = new_account(&mut base.test_runner);
+        let sponsor = NonFungibleGlobalId::from_public_key(&sponsor_account.public_key);
+
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(sponsor_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "matching_amount")
+            .call_method_with_name_lookup(collection_component, "fund_matching_pool", |lookup| {
+                (lookup.bucket("matching_amount"), sponsor.clone())
+            });
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "matching_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &sponsor_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Donate 40 XRD, which is matched in full
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(40))
+            .take_from_worktop(XRD, dec!(40), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "matching_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id: NonFungibleLocalId;
+        {
+            let mut trophies = base
+                .test_runner
+                .inspect_non_fungible_vault(trophy_vault[0])
+                .unwrap()
+                .1;
+
+            trophy_id = trophies.next().unwrap().clone();
+        }
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id.clone());
+
+        assert_eq!(trophy_data.transactions[0].amount, dec!(40));
+        assert_eq!(trophy_data.transactions[0].matched, dec!(40));
+
+        // Donate 40 XRD again, which is matched up to the cap
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(40))
+            .take_from_worktop(XRD, dec!(40), "donation_amount")
+            .create_proof_from_account_of_non_fungible(
+                donation_account.wallet_address,
+                NonFungibleGlobalId::new(base.trophy_resource_address, trophy_id.clone()),
+            )
+            .create_proof_from_auth_zone_of_non_fungibles(
+                base.trophy_resource_address,
+                vec![trophy_id.clone()],
+                "proof",
+            )
+            .call_method_with_name_lookup(collection_component, "donate_update", |lookup| {
+                (
+                    lookup.bucket("donation_amount"),
+                    lookup.proof("proof"),
+                    None::<String>,
+                )
+            })
+            .deposit_batch(donation_account.wallet_address)
+            .call_method(
+                collection_component,
+                "get_matching_pool",
+                manifest_args!(XRD),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "matching_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        let pool: Decimal = receipt.expect_commit_success().output(6);
+        assert_eq!(pool, dec!(50));
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id);
+
+        assert_eq!(trophy_data.transactions[1].amount, dec!(40));
+        assert_eq!(trophy_data.transactions[1].matched, dec!(10));
+
+        // The creator withdraws the donations and the matches, after the fee
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(collection_component, "withdraw_donations", manifest_args!())
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "matching_success_6",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(creator_badge_account.wallet_address, XRD),
+            dec!(10124.8)
+        );
+
+        // The terms of the matching can not be changed while the pool holds funds
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "set_matching",
+                manifest_args!(Some(Matching {
+                    ratio: dec!(100),
+                    cap: dec!(1000)
+                })),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "matching_success_7",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Nor can the matching be stopped while the pool holds funds
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "set_matching",
+                manifest_args!(None::<Matching>),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "matching_success_8",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Close the collection, the creator can not reclaim the pool for the sponsor
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(collection_component, "close_collection", manifest_args!())
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "matching_success_9",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                collection_component,
+                "reclaim_matching_pool",
+                manifest_args!(sponsor.clone()),
+            )
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "matching_success_10",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // The sponsor reclaims what is left of the pool once the collection is closed
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                collection_component,
+                "reclaim_matching_pool",
+                manifest_args!(sponsor),
+            )
+            .deposit_batch(sponsor_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "matching_success_11",
+            vec![NonFungibleGlobalId::from_public_key(
+                &sponsor_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(sponsor_account.wallet_address, XRD),
+            dec!(9950)
+        );
+    }
+
+    #[test]
+    fn donate_gift_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_gift_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account, and the account of the recipient of the gift
+        let donation_account = new_account(&mut base.test_runner);
+        let recipient_account = new_account(&mut base.test_runner);
+
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_gift", |lookup| {
+                (
+                    lookup.bucket("donation_amount"),
+                    NonFungibleGlobalId::from_public_key(&donation_account.public_key),
+                    recipient_account.wallet_address,
+                    Some("Happy birthday!".to_string()),
+                )
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_gift_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // The gifter receives the thanks tokens
+        assert_eq!(
+            base.test_runner.get_component_balance(
+                donation_account.wallet_address,
+                base.thanks_token_resource_address
+            ),
+            dec!(100)
+        );
+
+        assert_eq!(
+            base.test_runner.get_component_balance(
+                donation_account.wallet_address,
+                base.trophy_resource_address
+            ),
+            dec!(0)
+        );
+
+        // The recipient receives the trophy and the membership
+        assert_eq!(
+            base.test_runner.get_component_balance(
+                recipient_account.wallet_address,
+                base.membership_resource_address
+            ),
+            dec!(1)
+        );
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            recipient_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id: NonFungibleLocalId;
+        {
+            let mut trophies = base
+                .test_runner
+                .inspect_non_fungible_vault(trophy_vault[0])
+                .unwrap()
+                .1;
+
+            trophy_id = trophies.next().unwrap().clone();
+        }
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id);
+
+        assert_eq!(
+            trophy_data.transactions[0].gifter,
+            Some(NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key
+            ))
+        );
+
+        // A gift can not be given in the name of someone else
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_gift", |lookup| {
+                (
+                    lookup.bucket("donation_amount"),
+                    NonFungibleGlobalId::from_public_key(&recipient_account.public_key),
+                    recipient_account.wallet_address,
+                    None::<String>,
+                )
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "donate_gift_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
 }
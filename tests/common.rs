@@ -64,12 +64,22 @@ pub fn new_account(test_runner: &mut DefaultTestRunner) -> Account {
 
 #[cfg(test)]
 pub fn mint_creator_badge(base: &mut TestRunner, account: &Account) -> NonFungibleGlobalId {
+    mint_creator_badge_with_slug(base, account, "Kansuler", "kansuler")
+}
+
+#[cfg(test)]
+pub fn mint_creator_badge_with_slug(
+    base: &mut TestRunner,
+    account: &Account,
+    creator_name: &str,
+    creator_slug: &str,
+) -> NonFungibleGlobalId {
     // Test the repository component via the new function.
     let manifest = ManifestBuilder::new()
         .call_method(
             base.repository_component,
             "mint_creator_badge",
-            manifest_args!("Kansuler", "kansuler"),
+            manifest_args!(creator_name, creator_slug),
         )
         .assert_worktop_contains_any(base.creator_badge_resource_address)
         .deposit_batch(account.wallet_address);
@@ -114,9 +124,11 @@ pub struct TestRunner {
     pub package_owner_badge_global_id: NonFungibleGlobalId,
     pub creator_badge_resource_address: ResourceAddress,
     pub repository_owner_badge_global_id: NonFungibleGlobalId,
+    pub emergency_badge_global_id: NonFungibleGlobalId,
     pub membership_resource_address: ResourceAddress,
     pub trophy_resource_address: ResourceAddress,
     pub thanks_token_resource_address: ResourceAddress,
+    pub redemption_receipt_resource_address: ResourceAddress,
 }
 
 #[cfg(test)]
@@ -195,6 +207,31 @@ pub fn new_runner() -> TestRunner {
         roles: RoleAssignmentInit::default(),
     };
 
+    let mut emergency_badge_metadata = BTreeMap::new();
+    emergency_badge_metadata.insert(
+        "name".to_owned(),
+        MetadataValue::String("Backeum Emergency Badges".to_owned()),
+    );
+    emergency_badge_metadata.insert(
+        "description".to_owned(),
+        MetadataValue::String(
+            "Grants emergency pausing of backeum repository components".to_owned(),
+        ),
+    );
+    emergency_badge_metadata.insert(
+        "info_url".to_owned(),
+        MetadataValue::Url(UncheckedUrl("https://staging.backeum.com".to_owned())),
+    );
+    emergency_badge_metadata.insert(
+        "tags".to_owned(),
+        MetadataValue::StringArray(vec!["backeum".to_owned()]),
+    );
+
+    let emergency_badge_metadata = ModuleConfig {
+        init: emergency_badge_metadata.into(),
+        roles: RoleAssignmentInit::default(),
+    };
+
     // Create an owner badge used for repository component.
     let manifest = ManifestBuilder::new()
         .create_ruid_non_fungible_resource(
@@ -217,6 +254,16 @@ pub fn new_runner() -> TestRunner {
                 description: "Owner badge for components instantiated on Backeum".to_owned(),
             }]),
         )
+        .create_ruid_non_fungible_resource(
+            OwnerRole::None,
+            false,
+            emergency_badge_metadata,
+            Default::default(),
+            Some([Nft {
+                name: "Badge".to_owned(),
+                description: "Emergency badge for components instantiated on Backeum".to_owned(),
+            }]),
+        )
         .deposit_batch(owner_account.wallet_address);
 
     // Execute the manifest.
@@ -268,6 +315,22 @@ pub fn new_runner() -> TestRunner {
         repository_owner_badge_id.clone(),
     );
 
+    // Get the emergency badge, held by the owner account.
+    let emergency_badge_resource_address = result.new_resource_addresses()[2];
+
+    let emergency_badge_vault = test_runner.get_component_vaults(
+        owner_account.wallet_address,
+        emergency_badge_resource_address,
+    );
+    let emergency_badge_id = test_runner
+        .inspect_non_fungible_vault(emergency_badge_vault[0])
+        .unwrap()
+        .1
+        .next()
+        .unwrap();
+    let emergency_badge_global_id =
+        NonFungibleGlobalId::new(emergency_badge_resource_address, emergency_badge_id.clone());
+
     // Upload package
     let (code, definition) = Compile::compile(this_package!());
     let manifest = ManifestBuilder::new().publish_package_with_owner(
@@ -298,6 +361,7 @@ pub fn new_runner() -> TestRunner {
             manifest_args!(
                 "https://localhost:8080",
                 repository_owner_badge_resource_address,
+                emergency_badge_resource_address,
                 owner_account.wallet_address,
             ),
         )
@@ -331,6 +395,9 @@ pub fn new_runner() -> TestRunner {
     // Get the thanks token resource address.
     let thanks_token_resource_address = result.new_resource_addresses()[3];
 
+    // Get the redemption receipt resource address.
+    let redemption_receipt_resource_address = result.new_resource_addresses()[5];
+
     // Set metadata on dapp definition
     let manifest = ManifestBuilder::new()
         .set_metadata(
@@ -387,8 +454,10 @@ pub fn new_runner() -> TestRunner {
         package_owner_badge_global_id,
         creator_badge_resource_address: creator_badge_resource_address,
         repository_owner_badge_global_id,
+        emergency_badge_global_id,
         membership_resource_address,
         trophy_resource_address,
         thanks_token_resource_address,
+        redemption_receipt_resource_address,
     }
 }
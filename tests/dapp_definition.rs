@@ -2,6 +2,7 @@
 mod common;
 use common::{execute_manifest, mint_creator_badge, new_account, new_runner};
 
+use backeum_blueprint::data::FundingGoal;
 use scrypto::prelude::*;
 use transaction::builder::*;
 
@@ -38,6 +39,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -71,7 +73,7 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
             .take_from_worktop(XRD, dec!(100), "donation_amount")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount"),)
+                (lookup.bucket("donation_amount"), None::<String>)
             })
             .assert_worktop_contains(base.trophy_resource_address, dec!(1))
             .assert_worktop_contains(base.thanks_token_resource_address, dec!(100))
@@ -155,6 +157,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -1,8 +1,15 @@
 #[path = "./common.rs"]
 mod common;
-use common::{execute_manifest, mint_creator_badge, new_account, new_runner, Nft};
+use common::{
+    execute_manifest, mint_creator_badge, mint_creator_badge_with_slug, new_account, new_runner,
+    Nft,
+};
 
-use backeum_blueprint::data::{Membership, Trophy};
+use backeum_blueprint::data::{
+    Creator, DonationLimits, FundingGoal, Membership, Milestone, RedemptionReceipt, Subscription,
+    Tier, Trophy, Vesting,
+};
+use backeum_blueprint::events::CollectionCreatedEvent;
 use scrypto::prelude::*;
 use transaction::builder::ManifestBuilder;
 
@@ -36,6 +43,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -195,6 +203,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -225,7 +234,13 @@ mod tests {
             .call_method(
                 base.repository_component,
                 "new_collection_component_and_badge",
-                manifest_args!("Kansuler", "kansuler", "Trophy name", "Trophy description"),
+                manifest_args!(
+                    "Kansuler",
+                    "kansuler",
+                    "Trophy name",
+                    "Trophy description",
+                    None::<FundingGoal>
+                ),
             )
             .assert_worktop_contains(base.creator_badge_resource_address, dec!(1))
             .deposit_batch(creator_badge_account.wallet_address);
@@ -320,6 +335,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -343,12 +359,12 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(500))
             .take_from_worktop(XRD, dec!(250), "donation_amount_1")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount_1"),)
+                (lookup.bucket("donation_amount_1"), None::<String>)
             })
             .assert_worktop_contains(base.trophy_resource_address, dec!(1))
             .take_from_worktop(XRD, dec!(250), "donation_amount_2")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount_2"),)
+                (lookup.bucket("donation_amount_2"), None::<String>)
             })
             .assert_worktop_contains(base.trophy_resource_address, dec!(2))
             .take_all_from_worktop(base.trophy_resource_address, "trophies")
@@ -441,7 +457,7 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(500))
             .take_from_worktop(XRD, dec!(500), "donation_amount_1")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount_1"),)
+                (lookup.bucket("donation_amount_1"), None::<String>)
             })
             .withdraw_non_fungibles_from_account(
                 donation_account.wallet_address,
@@ -563,6 +579,7 @@ mod tests {
                         lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
             );
@@ -586,12 +603,12 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(500))
             .take_from_worktop(XRD, dec!(250), "donation_amount_1")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount_1"),)
+                (lookup.bucket("donation_amount_1"), None::<String>)
             })
             .assert_worktop_contains(base.membership_resource_address, dec!(1))
             .take_from_worktop(XRD, dec!(250), "donation_amount_2")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount_2"),)
+                (lookup.bucket("donation_amount_2"), None::<String>)
             })
             .assert_worktop_contains(base.membership_resource_address, dec!(2))
             .take_all_from_worktop(base.membership_resource_address, "memberships")
@@ -680,7 +697,7 @@ mod tests {
             .withdraw_from_account(donation_account.wallet_address, XRD, dec!(500))
             .take_from_worktop(XRD, dec!(500), "donation_amount_1")
             .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount_1"),)
+                (lookup.bucket("donation_amount_1"), None::<String>)
             })
             .withdraw_non_fungibles_from_account(
                 donation_account.wallet_address,
@@ -770,7 +787,7 @@ mod tests {
     }
 
     #[test]
-    fn merge_trophies_failure_different_collection() {
+    fn merge_memberships_success_perpetual() {
         let mut base = new_runner();
 
         // Create an component admin account
@@ -780,222 +797,3704 @@ mod tests {
             creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
         }
 
-        // Create donation account
-        let donation_account = new_account(&mut base.test_runner);
-
-        // Create two collection components
+        // Create a collection component
         let manifest = ManifestBuilder::new()
             .create_proof_from_account_of_non_fungible(
                 creator_badge_account.wallet_address,
-                creator_badge_badge_id,
+                creator_badge_badge_id.clone(),
             )
             .pop_from_auth_zone("creator_badge_proof")
-            .clone_proof("creator_badge_proof", "creator_badge_proof_1")
-            .clone_proof("creator_badge_proof", "creator_badge_proof_2")
             .call_method_with_name_lookup(
                 base.repository_component,
                 "new_collection_component",
                 |lookup| {
                     (
-                        lookup.proof("creator_badge_proof_1"),
+                        lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_memberships_success_perpetual_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Donate and mint a membership before the creator offers subscriptions, which does not
+        // expire
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_memberships_success_perpetual_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let membership_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.membership_resource_address,
+        );
+
+        let membership_id = base
+            .test_runner
+            .inspect_non_fungible_vault(membership_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        // Offer memberships as a subscription of 30 days for 50 XRD
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
             )
+            .pop_from_auth_zone("creator_badge_proof")
             .call_method_with_name_lookup(
                 base.repository_component,
-                "new_collection_component",
+                "set_subscription",
                 |lookup| {
                     (
-                        lookup.proof("creator_badge_proof_2"),
-                        "Trophy name",
-                        "Kansulers trophy",
+                        lookup.proof("creator_badge_proof"),
+                        Some(Subscription {
+                            resource_address: XRD,
+                            price: dec!(50),
+                            days: 30,
+                        }),
                     )
                 },
             );
 
-        // Execute it
         let receipt = execute_manifest(
             &mut base.test_runner,
             manifest,
-            "merge_trophies_failure_different_collection_1merge_failure_different_collection",
+            "merge_memberships_success_perpetual_3",
             vec![NonFungibleGlobalId::from_public_key(
                 &creator_badge_account.public_key,
             )],
             true,
         );
 
-        // Get the resource address
-        let collection_component_1 = receipt.expect_commit_success().new_component_addresses()[0];
-        let collection_component_2 = receipt.expect_commit_success().new_component_addresses()[1];
+        receipt.expect_commit_success();
 
-        // Donate and mint trophy
+        // Donate and mint a membership that expires, and merge it with the perpetual membership
         let manifest = ManifestBuilder::new()
-            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(500))
-            .take_from_worktop(XRD, dec!(250), "donation_amount_1")
-            .call_method_with_name_lookup(collection_component_1, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount_1"),)
-            })
-            .assert_worktop_contains(base.trophy_resource_address, dec!(1))
-            .take_from_worktop(XRD, dec!(250), "donation_amount_2")
-            .call_method_with_name_lookup(collection_component_2, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount_2"),)
-            })
-            .assert_worktop_contains(base.trophy_resource_address, dec!(2))
-            .take_all_from_worktop(base.trophy_resource_address, "trophies")
-            .call_method_with_name_lookup(base.repository_component, "merge_trophies", |lookup| {
-                (lookup.bucket("trophies"),)
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(50))
+            .take_from_worktop(XRD, dec!(50), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
             })
-            .assert_worktop_contains(base.trophy_resource_address, dec!(1))
-            .assert_worktop_contains(base.thanks_token_resource_address, dec!(500))
+            .withdraw_non_fungibles_from_account(
+                donation_account.wallet_address,
+                base.membership_resource_address,
+                vec![membership_id],
+            )
+            .assert_worktop_contains(base.membership_resource_address, dec!(2))
+            .take_all_from_worktop(base.membership_resource_address, "memberships")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "merge_memberships",
+                |lookup| (lookup.bucket("memberships"),),
+            )
+            .assert_worktop_contains(base.membership_resource_address, dec!(1))
             .deposit_batch(donation_account.wallet_address);
 
         let receipt = execute_manifest(
             &mut base.test_runner,
             manifest,
-            "merge_trophies_failure_different_collection_2",
+            "merge_memberships_success_perpetual_4",
             vec![NonFungibleGlobalId::from_public_key(
                 &donation_account.public_key,
             )],
             true,
         );
 
-        receipt.expect_commit_failure();
+        receipt.expect_commit_success();
 
-        assert_eq!(
-            base.test_runner.get_component_balance(
-                donation_account.wallet_address,
-                base.trophy_resource_address
-            ),
-            dec!(0)
+        let membership_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.membership_resource_address,
         );
-        assert_eq!(
-            base.test_runner
-                .get_component_balance(donation_account.wallet_address, XRD),
-            dec!(10000)
+
+        let merged_id = base
+            .test_runner
+            .inspect_non_fungible_vault(membership_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        // The merged membership is perpetual, as one of the memberships was perpetual
+        let membership_data: Membership = base
+            .test_runner
+            .get_non_fungible_data(base.membership_resource_address, merged_id.clone());
+
+        assert_eq!(membership_data.expires_at, None);
+        assert_eq!(membership_data.donated, dec!(150));
+
+        // A membership without an expiry is always active
+        let manifest = ManifestBuilder::new().call_method(
+            base.repository_component,
+            "is_membership_active",
+            manifest_args!(merged_id),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_memberships_success_perpetual_5",
+            vec![],
+            true,
         );
+
+        let active: bool = receipt.expect_commit_success().output(0);
+        assert!(active);
     }
 
     #[test]
-    fn merge_memberships_failure_different_creator() {
+    fn merge_trophies_success_updated_details() {
         let mut base = new_runner();
 
+        base.test_runner
+            .advance_to_round_at_timestamp(Round::of(50), 1699093188267);
         // Create an component admin account
         let creator_badge_account = new_account(&mut base.test_runner);
-        let creator_badge_1_badge_id: NonFungibleGlobalId;
-        {
-            creator_badge_1_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
-        }
-        let creator_badge_2_badge_id: NonFungibleGlobalId;
+        let creator_badge_badge_id: NonFungibleGlobalId;
         {
-            creator_badge_2_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
         }
 
-        assert_ne!(
-            creator_badge_1_badge_id.local_id().to_string(),
-            creator_badge_2_badge_id.local_id().to_string()
-        );
-
         // Create donation account
         let donation_account = new_account(&mut base.test_runner);
 
-        // Create two collection components
+        // Create a collection component
         let manifest = ManifestBuilder::new()
             .create_proof_from_account_of_non_fungible(
                 creator_badge_account.wallet_address,
-                creator_badge_1_badge_id,
+                creator_badge_badge_id.clone(),
             )
-            .pop_from_auth_zone("creator_badge_proof_1")
+            .pop_from_auth_zone("creator_badge_proof")
             .call_method_with_name_lookup(
                 base.repository_component,
                 "new_collection_component",
                 |lookup| {
                     (
-                        lookup.proof("creator_badge_proof_1"),
+                        lookup.proof("creator_badge_proof"),
                         "Trophy name",
                         "Kansulers trophy",
+                        None::<FundingGoal>,
                     )
                 },
-            )
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_trophies_success_updated_details_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donate and mint a trophy with the initial details
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_trophies_success_updated_details_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        base.test_runner
+            .advance_to_round_at_timestamp(Round::of(51), 1699142400000); // 2023-11-05
+
+        // Update the trophy details without re-stamping the existing trophy
+        let manifest = ManifestBuilder::new()
             .create_proof_from_account_of_non_fungible(
                 creator_badge_account.wallet_address,
-                creator_badge_2_badge_id,
+                creator_badge_badge_id,
             )
-            .pop_from_auth_zone("creator_badge_proof_2")
-            .call_method_with_name_lookup(
-                base.repository_component,
-                "new_collection_component",
-                |lookup| {
-                    (
-                        lookup.proof("creator_badge_proof_2"),
-                        "Trophy name",
-                        "Kansulers trophy",
-                    )
-                },
+            .call_method(
+                collection_component,
+                "update_trophy_details",
+                manifest_args!("Fixed trophy name", "Kansulers fixed trophy"),
             );
 
-        // Execute it
         let receipt = execute_manifest(
             &mut base.test_runner,
             manifest,
-            "merge_memberships_failure_different_creator_1",
+            "merge_trophies_success_updated_details_3",
             vec![NonFungibleGlobalId::from_public_key(
                 &creator_badge_account.public_key,
             )],
             true,
         );
 
-        // Get the resource address
-        let collection_component_1 = receipt.expect_commit_success().new_component_addresses()[0];
-        let collection_component_2 = receipt.expect_commit_success().new_component_addresses()[1];
+        receipt.expect_commit_success();
 
-        // Donate and mint trophy
+        // Donate and mint a trophy with the updated details, and merge both trophies
         let manifest = ManifestBuilder::new()
-            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(500))
-            .take_from_worktop(XRD, dec!(250), "donation_amount_1")
-            .call_method_with_name_lookup(collection_component_1, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount_1"),)
-            })
-            .assert_worktop_contains(base.membership_resource_address, dec!(1))
-            .take_from_worktop(XRD, dec!(250), "donation_amount_2")
-            .call_method_with_name_lookup(collection_component_2, "donate_mint", |lookup| {
-                (lookup.bucket("donation_amount_2"),)
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
             })
-            .assert_worktop_contains(base.membership_resource_address, dec!(2))
-            .take_all_from_worktop(base.membership_resource_address, "memberships")
-            .call_method_with_name_lookup(
-                base.repository_component,
-                "merge_memberships",
-                |lookup| (lookup.bucket("memberships"),),
+            .withdraw_from_account(
+                donation_account.wallet_address,
+                base.trophy_resource_address,
+                dec!(1),
             )
-            .assert_worktop_contains(base.membership_resource_address, dec!(1))
-            .assert_worktop_contains(base.thanks_token_resource_address, dec!(500))
+            .take_all_from_worktop(base.trophy_resource_address, "trophies")
+            .call_method_with_name_lookup(base.repository_component, "merge_trophies", |lookup| {
+                (lookup.bucket("trophies"),)
+            })
+            .assert_worktop_contains(base.trophy_resource_address, dec!(1))
             .deposit_batch(donation_account.wallet_address);
 
         let receipt = execute_manifest(
             &mut base.test_runner,
             manifest,
-            "merge_memberships_failure_different_creator_2",
+            "merge_trophies_success_updated_details_4",
             vec![NonFungibleGlobalId::from_public_key(
                 &donation_account.public_key,
             )],
             true,
         );
 
-        receipt.expect_commit_failure();
+        receipt.expect_commit_success();
 
-        assert_eq!(
-            base.test_runner.get_component_balance(
-                donation_account.wallet_address,
-                base.trophy_resource_address
-            ),
-            dec!(0)
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
         );
-        assert_eq!(
-            base.test_runner
-                .get_component_balance(donation_account.wallet_address, XRD),
-            dec!(10000)
+
+        let trophy_id = base
+            .test_runner
+            .inspect_non_fungible_vault(trophy_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id);
+
+        assert_eq!(trophy_data.name, "Fixed trophy name");
+        assert_eq!(trophy_data.description, "Kansulers fixed trophy");
+        assert_eq!(trophy_data.created, "2023-11-04");
+        assert_eq!(trophy_data.donated, dec!(200));
+    }
+
+    #[test]
+    fn merge_trophies_success_tier() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_trophies_success_tier_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Set the donation tiers of the collection
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(
+                collection_component,
+                "set_tiers",
+                manifest_args!(vec![
+                    Tier {
+                        name: "Bronze".to_owned(),
+                        threshold: dec!(10),
+                    },
+                    Tier {
+                        name: "Silver".to_owned(),
+                        threshold: dec!(100),
+                    },
+                ]),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_trophies_success_tier_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Mint two bronze trophies, and merge them into a silver trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(120))
+            .take_from_worktop(XRD, dec!(60), "donation_amount_1")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount_1"), None::<String>)
+            })
+            .take_from_worktop(XRD, dec!(60), "donation_amount_2")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount_2"), None::<String>)
+            })
+            .take_all_from_worktop(base.trophy_resource_address, "trophies")
+            .call_method_with_name_lookup(base.repository_component, "merge_trophies", |lookup| {
+                (lookup.bucket("trophies"),)
+            })
+            .assert_worktop_contains(base.trophy_resource_address, dec!(1))
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_trophies_success_tier_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id = base
+            .test_runner
+            .inspect_non_fungible_vault(trophy_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id);
+
+        assert_eq!(trophy_data.donated, dec!(120));
+        assert_eq!(trophy_data.tier, Some("Silver".to_owned()));
+    }
+
+    #[test]
+    fn merge_trophies_failure_different_collection() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create two collection components
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .clone_proof("creator_badge_proof", "creator_badge_proof_1")
+            .clone_proof("creator_badge_proof", "creator_badge_proof_2")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof_1"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            )
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof_2"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        // Execute it
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_trophies_failure_different_collection_1merge_failure_different_collection",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        // Get the resource address
+        let collection_component_1 = receipt.expect_commit_success().new_component_addresses()[0];
+        let collection_component_2 = receipt.expect_commit_success().new_component_addresses()[1];
+
+        // Donate and mint trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(500))
+            .take_from_worktop(XRD, dec!(250), "donation_amount_1")
+            .call_method_with_name_lookup(collection_component_1, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount_1"), None::<String>)
+            })
+            .assert_worktop_contains(base.trophy_resource_address, dec!(1))
+            .take_from_worktop(XRD, dec!(250), "donation_amount_2")
+            .call_method_with_name_lookup(collection_component_2, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount_2"), None::<String>)
+            })
+            .assert_worktop_contains(base.trophy_resource_address, dec!(2))
+            .take_all_from_worktop(base.trophy_resource_address, "trophies")
+            .call_method_with_name_lookup(base.repository_component, "merge_trophies", |lookup| {
+                (lookup.bucket("trophies"),)
+            })
+            .assert_worktop_contains(base.trophy_resource_address, dec!(1))
+            .assert_worktop_contains(base.thanks_token_resource_address, dec!(500))
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_trophies_failure_different_collection_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        assert_eq!(
+            base.test_runner.get_component_balance(
+                donation_account.wallet_address,
+                base.trophy_resource_address
+            ),
+            dec!(0)
+        );
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(donation_account.wallet_address, XRD),
+            dec!(10000)
+        );
+    }
+
+    #[test]
+    fn merge_memberships_failure_different_creator() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_1_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_1_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+        let creator_badge_2_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_2_badge_id = mint_creator_badge_with_slug(
+                &mut base,
+                &creator_badge_account,
+                "Kansuler",
+                "kansuler-2",
+            );
+        }
+
+        assert_ne!(
+            creator_badge_1_badge_id.local_id().to_string(),
+            creator_badge_2_badge_id.local_id().to_string()
+        );
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create two collection components
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_1_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof_1")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof_1"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            )
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_2_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof_2")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof_2"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        // Execute it
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_memberships_failure_different_creator_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        // Get the resource address
+        let collection_component_1 = receipt.expect_commit_success().new_component_addresses()[0];
+        let collection_component_2 = receipt.expect_commit_success().new_component_addresses()[1];
+
+        // Donate and mint trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(500))
+            .take_from_worktop(XRD, dec!(250), "donation_amount_1")
+            .call_method_with_name_lookup(collection_component_1, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount_1"), None::<String>)
+            })
+            .assert_worktop_contains(base.membership_resource_address, dec!(1))
+            .take_from_worktop(XRD, dec!(250), "donation_amount_2")
+            .call_method_with_name_lookup(collection_component_2, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount_2"), None::<String>)
+            })
+            .assert_worktop_contains(base.membership_resource_address, dec!(2))
+            .take_all_from_worktop(base.membership_resource_address, "memberships")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "merge_memberships",
+                |lookup| (lookup.bucket("memberships"),),
+            )
+            .assert_worktop_contains(base.membership_resource_address, dec!(1))
+            .assert_worktop_contains(base.thanks_token_resource_address, dec!(500))
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_memberships_failure_different_creator_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        assert_eq!(
+            base.test_runner.get_component_balance(
+                donation_account.wallet_address,
+                base.trophy_resource_address
+            ),
+            dec!(0)
+        );
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(donation_account.wallet_address, XRD),
+            dec!(10000)
+        );
+    }
+
+    #[test]
+    fn set_fee_rate_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Set the repository fee rate to 10%
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "set_fee_rate",
+                manifest_args!(dec!(0.1)),
+            )
+            .call_method(base.repository_component, "get_fee_rate", manifest_args!());
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_fee_rate_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        let fee_rate: Decimal = receipt.expect_commit_success().output(2);
+        assert_eq!(fee_rate, dec!(0.1));
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_fee_rate_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donate and mint trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_fee_rate_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Withdraw the fees taken by the collection
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(collection_component, "withdraw_fees", manifest_args!())
+            .deposit_batch(base.owner_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_fee_rate_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(base.owner_account.wallet_address, XRD),
+            dec!(10010)
+        );
+    }
+
+    #[test]
+    fn set_creator_fee_rate_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Set the fee rate of the creator to 0%
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "set_creator_fee_rate",
+                manifest_args!(creator_badge_badge_id.clone(), Some(dec!(0))),
+            )
+            .call_method(
+                base.repository_component,
+                "get_creator_fee_rate",
+                manifest_args!(creator_badge_badge_id.clone()),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_creator_fee_rate_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        let fee_rate: Option<Decimal> = receipt.expect_commit_success().output(2);
+        assert_eq!(fee_rate, Some(dec!(0)));
+
+        // Collections of the creator are set up with the fee rate of the creator
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_creator_fee_rate_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        let manifest = ManifestBuilder::new().call_method(
+            collection_component,
+            "get_fee_rate",
+            manifest_args!(),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_creator_fee_rate_success_3",
+            vec![],
+            true,
+        );
+
+        let fee_rate: Decimal = receipt.expect_commit_success().output(0);
+        assert_eq!(fee_rate, dec!(0));
+    }
+
+    #[test]
+    fn set_creator_fee_rate_failure_auth() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // The creator can not set its own fee rate
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "set_creator_fee_rate",
+                manifest_args!(creator_badge_badge_id, Some(dec!(0))),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_creator_fee_rate_failure_auth_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn set_fee_rate_failure_auth() {
+        let mut base = new_runner();
+
+        // Create an account without the repository owner badge
+        let account = new_account(&mut base.test_runner);
+
+        let manifest = ManifestBuilder::new().call_method(
+            base.repository_component,
+            "set_fee_rate",
+            manifest_args!(dec!(0)),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_fee_rate_failure_auth_1",
+            vec![NonFungibleGlobalId::from_public_key(&account.public_key)],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn set_fee_rate_failure_bounds() {
+        let mut base = new_runner();
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "set_fee_rate",
+                manifest_args!(dec!(1.5)),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_fee_rate_failure_bounds_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn set_donation_limits_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Set the repository donation limits
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "set_donation_limits",
+                manifest_args!(
+                    XRD,
+                    Some(DonationLimits {
+                        minimum: dec!(10),
+                        maximum: Some(dec!(500)),
+                    })
+                ),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_donation_limits_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_donation_limits_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donating below the minimum fails
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(5))
+            .take_from_worktop(XRD, dec!(5), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_donation_limits_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Donating above the maximum fails
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(600))
+            .take_from_worktop(XRD, dec!(600), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_donation_limits_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Donating within the limits succeeds
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_donation_limits_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(donation_account.wallet_address, XRD),
+            dec!(9900)
+        );
+    }
+
+    #[test]
+    fn set_donation_limits_failure_bounds() {
+        let mut base = new_runner();
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "set_donation_limits",
+                manifest_args!(
+                    XRD,
+                    Some(DonationLimits {
+                        minimum: dec!(100),
+                        maximum: Some(dec!(10)),
+                    })
+                ),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_donation_limits_failure_bounds_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn set_donation_limits_failure_zero_minimum() {
+        let mut base = new_runner();
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "set_donation_limits",
+                manifest_args!(
+                    XRD,
+                    Some(DonationLimits {
+                        minimum: dec!(0),
+                        maximum: None,
+                    })
+                ),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "set_donation_limits_failure_zero_minimum_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn new_collection_component_event_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "new_collection_component_event_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let result = receipt.expect_commit_success();
+        let collection_component = result.new_component_addresses()[0];
+
+        let (_, data) = result
+            .application_events
+            .iter()
+            .find(|(event_type_identifier, _)| {
+                base.test_runner.event_name(event_type_identifier) == "CollectionCreatedEvent"
+            })
+            .unwrap();
+        let event: CollectionCreatedEvent = scrypto_decode(data).unwrap();
+
+        let collection_id = AddressBech32Encoder::new(&NetworkDefinition::simulator())
+            .encode(&collection_component.to_vec())
+            .unwrap();
+
+        assert_eq!(event.collection_id, collection_id);
+        assert_eq!(event.creator, creator_badge_badge_id);
+    }
+
+    #[test]
+    fn get_collections_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create two collection components
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof_1")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof_1"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            )
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof_2")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof_2"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "get_collections_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let result = receipt.expect_commit_success();
+        let collection_component_1 = result.new_component_addresses()[0];
+        let collection_component_2 = result.new_component_addresses()[1];
+
+        // Query the registry of the repository
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                base.repository_component,
+                "get_collections",
+                manifest_args!(creator_badge_badge_id.clone()),
+            )
+            .call_method(
+                base.repository_component,
+                "get_collection_creator",
+                manifest_args!(collection_component_2),
+            )
+            .call_method(
+                base.repository_component,
+                "is_collection",
+                manifest_args!(collection_component_1),
+            )
+            .call_method(
+                base.repository_component,
+                "is_collection",
+                manifest_args!(creator_badge_account.wallet_address),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "get_collections_success_2",
+            vec![],
+            true,
+        );
+
+        let result = receipt.expect_commit_success();
+
+        let collections: Vec<ComponentAddress> = result.output(0);
+        assert_eq!(
+            collections,
+            vec![collection_component_1, collection_component_2]
+        );
+
+        let creator: Option<NonFungibleGlobalId> = result.output(1);
+        assert_eq!(creator, Some(creator_badge_badge_id));
+
+        let is_collection: bool = result.output(2);
+        assert!(is_collection);
+
+        let is_collection: bool = result.output(3);
+        assert!(!is_collection);
+    }
+
+    #[test]
+    fn mint_creator_badge_failure_duplicate_slug() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        {
+            mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Mint another creator badge with the same slug
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                base.repository_component,
+                "mint_creator_badge",
+                manifest_args!("Kansuler", "kansuler"),
+            )
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "mint_creator_badge_failure_duplicate_slug_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn mint_creator_badge_failure_invalid_slug() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                base.repository_component,
+                "mint_creator_badge",
+                manifest_args!("Kansuler", "Kansuler Slug"),
+            )
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "mint_creator_badge_failure_invalid_slug_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn release_creator_slug_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Release the slug, and query the owner of it
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                base.repository_component,
+                "get_creator_slug_owner",
+                manifest_args!("kansuler"),
+            )
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "release_creator_slug",
+                manifest_args!("kansuler"),
+            )
+            .call_method(
+                base.repository_component,
+                "get_creator_slug_owner",
+                manifest_args!("kansuler"),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "release_creator_slug_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        let result = receipt.expect_commit_success();
+
+        let creator: Option<NonFungibleGlobalId> = result.output(0);
+        assert_eq!(creator, Some(creator_badge_badge_id.clone()));
+
+        let creator: Option<NonFungibleGlobalId> = result.output(3);
+        assert_eq!(creator, None);
+
+        let creator_data: Creator = base.test_runner.get_non_fungible_data(
+            base.creator_badge_resource_address,
+            creator_badge_badge_id.local_id().clone(),
+        );
+
+        assert_eq!(creator_data.creator_slug, "");
+
+        // The released slug can be reserved again
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                base.repository_component,
+                "mint_creator_badge",
+                manifest_args!("Kansuler", "kansuler"),
+            )
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "release_creator_slug_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+    }
+
+    #[test]
+    fn release_creator_slug_success_new_slug() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "release_creator_slug_success_new_slug_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Release the slug of the creator
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "release_creator_slug",
+                manifest_args!("kansuler"),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "release_creator_slug_success_new_slug_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Donations are not accepted while the creator has no slug
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "release_creator_slug_success_new_slug_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Set a new slug for the creator
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "update_creator_profile",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Kansuler Studio",
+                        "Independent music from Kansuler Studio",
+                        "kansuler-studio",
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "release_creator_slug_success_new_slug_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Donations are accepted again with the new slug
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "release_creator_slug_success_new_slug_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+    }
+
+    #[test]
+    fn release_creator_slug_failure_auth() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        {
+            mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        let manifest = ManifestBuilder::new().call_method(
+            base.repository_component,
+            "release_creator_slug",
+            manifest_args!("kansuler"),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "release_creator_slug_failure_auth_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn reassign_creator_slug_success() {
+        let mut base = new_runner();
+
+        // Create two creators, the second one takes over the slug of the first
+        let first_account = new_account(&mut base.test_runner);
+        let first_badge_id: NonFungibleGlobalId;
+        {
+            first_badge_id = mint_creator_badge(&mut base, &first_account);
+        }
+
+        let second_account = new_account(&mut base.test_runner);
+        let second_badge_id: NonFungibleGlobalId;
+        {
+            second_badge_id = mint_creator_badge_with_slug(
+                &mut base,
+                &second_account,
+                "Kansuler Studio",
+                "kansuler-studio",
+            );
+        }
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "reassign_creator_slug",
+                manifest_args!("kansuler", second_badge_id.clone()),
+            )
+            .call_method(
+                base.repository_component,
+                "get_creator_slug_owner",
+                manifest_args!("kansuler"),
+            )
+            .call_method(
+                base.repository_component,
+                "get_creator_slug_owner",
+                manifest_args!("kansuler-studio"),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "reassign_creator_slug_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        let result = receipt.expect_commit_success();
+
+        let creator: Option<NonFungibleGlobalId> = result.output(2);
+        assert_eq!(creator, Some(second_badge_id.clone()));
+
+        let creator: Option<NonFungibleGlobalId> = result.output(3);
+        assert_eq!(creator, None);
+
+        let creator_data: Creator = base.test_runner.get_non_fungible_data(
+            base.creator_badge_resource_address,
+            first_badge_id.local_id().clone(),
+        );
+
+        assert_eq!(creator_data.creator_slug, "");
+
+        let creator_data: Creator = base.test_runner.get_non_fungible_data(
+            base.creator_badge_resource_address,
+            second_badge_id.local_id().clone(),
+        );
+
+        assert_eq!(creator_data.creator_slug, "kansuler");
+    }
+
+    #[test]
+    fn update_creator_profile_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "update_creator_profile_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Update the creator profile
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "update_creator_profile",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Kansuler Studio",
+                        "Independent music from Kansuler Studio",
+                        "kansuler-studio",
+                    )
+                },
+            )
+            .call_method(
+                base.repository_component,
+                "get_creator_slug_owner",
+                manifest_args!("kansuler"),
+            )
+            .call_method(
+                base.repository_component,
+                "get_creator_slug_owner",
+                manifest_args!("kansuler-studio"),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "update_creator_profile_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let result = receipt.expect_commit_success();
+
+        let creator: Option<NonFungibleGlobalId> = result.output(3);
+        assert_eq!(creator, None);
+
+        let creator: Option<NonFungibleGlobalId> = result.output(4);
+        assert_eq!(creator, Some(creator_badge_badge_id.clone()));
+
+        let creator_data: Creator = base.test_runner.get_non_fungible_data(
+            base.creator_badge_resource_address,
+            creator_badge_badge_id.local_id().clone(),
+        );
+
+        assert_eq!(creator_data.name, "Creator Badge: Kansuler Studio");
+        assert_eq!(
+            creator_data.description,
+            "Independent music from Kansuler Studio"
+        );
+        assert_eq!(creator_data.creator_name, "Kansuler Studio");
+        assert_eq!(creator_data.creator_slug, "kansuler-studio");
+
+        // Donate and mint trophy, which carries the updated profile
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "update_creator_profile_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id = base
+            .test_runner
+            .inspect_non_fungible_vault(trophy_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        let trophy_data: Trophy = base
+            .test_runner
+            .get_non_fungible_data(base.trophy_resource_address, trophy_id);
+
+        assert_eq!(trophy_data.creator_name, "Kansuler Studio");
+        assert_eq!(trophy_data.creator_slug, "kansuler-studio");
+        assert_eq!(
+            trophy_data.info_url,
+            UncheckedUrl::of("https://localhost:8080/p/kansuler-studio".to_owned())
+        );
+    }
+
+    #[test]
+    fn update_creator_profile_failure_slug_taken() {
+        let mut base = new_runner();
+
+        // Create two creator badges
+        let creator_badge_account = new_account(&mut base.test_runner);
+        {
+            mint_creator_badge(&mut base, &creator_badge_account);
+        }
+        let other_creator_badge_account = new_account(&mut base.test_runner);
+        let other_creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            other_creator_badge_badge_id = mint_creator_badge_with_slug(
+                &mut base,
+                &other_creator_badge_account,
+                "Other",
+                "other",
+            );
+        }
+
+        // Update the profile of the other creator to a slug that is taken
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                other_creator_badge_account.wallet_address,
+                other_creator_badge_badge_id,
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "update_creator_profile",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Other",
+                        "Another creator on Backeum",
+                        "kansuler",
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "update_creator_profile_failure_slug_taken_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &other_creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn close_repository_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "close_repository_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Close the repository
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "close_repository",
+                manifest_args!(),
+            )
+            .call_method(base.repository_component, "get_closed", manifest_args!());
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "close_repository_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        let closed: Option<UtcDateTime> = receipt.expect_commit_success().output(2);
+        assert!(closed.is_some());
+
+        // Existing collections no longer accept donations
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "close_repository_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // No new creator badges can be minted
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                base.repository_component,
+                "mint_creator_badge",
+                manifest_args!("Backer", "backer"),
+            )
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "close_repository_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn emergency_pause_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "emergency_pause_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Pause the repository in an emergency
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.emergency_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "emergency_pause",
+                manifest_args!(),
+            )
+            .call_method(
+                base.repository_component,
+                "get_emergency_paused",
+                manifest_args!(),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "emergency_pause_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        let result = receipt.expect_commit_success();
+
+        let paused: Option<UtcDateTime> = result.output(2);
+        assert!(paused.is_some());
+
+        assert!(result
+            .application_events
+            .iter()
+            .any(|(event_type_identifier, _)| {
+                base.test_runner.event_name(event_type_identifier) == "EmergencyPausedEvent"
+            }));
+
+        // Collections no longer accept donations
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "emergency_pause_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // Unpause the repository
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.emergency_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "emergency_unpause",
+                manifest_args!(),
+            )
+            .call_method(
+                base.repository_component,
+                "get_emergency_paused",
+                manifest_args!(),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "emergency_pause_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        let paused: Option<UtcDateTime> = receipt.expect_commit_success().output(2);
+        assert!(paused.is_none());
+
+        // Collections accept donations again
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "emergency_pause_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+    }
+
+    #[test]
+    fn emergency_pause_failure_auth() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(
+                base.repository_component,
+                "emergency_pause",
+                manifest_args!(),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "emergency_pause_failure_auth_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // The repository owner badge does not grant the emergency role
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "emergency_pause",
+                manifest_args!(),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "emergency_pause_failure_auth_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn membership_levels_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "membership_levels_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Name the membership levels of the creator
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "set_membership_levels",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        vec![
+                            Tier {
+                                name: "Supporter".to_string(),
+                                threshold: dec!(1),
+                            },
+                            Tier {
+                                name: "Patron".to_string(),
+                                threshold: dec!(300),
+                            },
+                            Tier {
+                                name: "Champion".to_string(),
+                                threshold: dec!(1000),
+                            },
+                        ],
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "membership_levels_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Mint two Supporter memberships, and merge them into a Patron membership
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(500))
+            .take_from_worktop(XRD, dec!(250), "donation_amount_1")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount_1"), None::<String>)
+            })
+            .take_from_worktop(XRD, dec!(250), "donation_amount_2")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount_2"), None::<String>)
+            })
+            .take_all_from_worktop(base.membership_resource_address, "memberships")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "merge_memberships",
+                |lookup| (lookup.bucket("memberships"),),
+            )
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "membership_levels_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let membership_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.membership_resource_address,
+        );
+
+        let membership_id = base
+            .test_runner
+            .inspect_non_fungible_vault(membership_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        let membership_data: Membership = base
+            .test_runner
+            .get_non_fungible_data(base.membership_resource_address, membership_id.clone());
+
+        assert_eq!(membership_data.level, Some("Patron".to_string()));
+        assert_eq!(
+            membership_data.key_image_url,
+            UncheckedUrl::of(format!(
+                "https://localhost:8080/nft/membership/{}?donated=500&created=2023-11-04&level=Patron",
+                membership_data.creator_slug
+            ))
+        );
+
+        // Level up to Champion with another donation
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(500))
+            .take_from_worktop(XRD, dec!(500), "donation_amount")
+            .create_proof_from_account_of_non_fungible(
+                donation_account.wallet_address,
+                NonFungibleGlobalId::new(base.membership_resource_address, membership_id.clone()),
+            )
+            .create_proof_from_auth_zone_of_non_fungibles(
+                base.membership_resource_address,
+                vec![membership_id.clone()],
+                "membership_proof",
+            )
+            .call_method_with_name_lookup(
+                collection_component,
+                "donate_mint_with_membership",
+                |lookup| {
+                    (
+                        lookup.bucket("donation_amount"),
+                        lookup.proof("membership_proof"),
+                        None::<String>,
+                    )
+                },
+            )
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "membership_levels_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let membership_data: Membership = base
+            .test_runner
+            .get_non_fungible_data(base.membership_resource_address, membership_id);
+
+        assert_eq!(membership_data.level, Some("Champion".to_string()));
+    }
+
+    #[test]
+    fn split_trophy_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Donate and mint trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id = base
+            .test_runner
+            .inspect_non_fungible_vault(trophy_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        // Donate again to the same trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(200))
+            .take_from_worktop(XRD, dec!(200), "donation_amount")
+            .create_proof_from_account_of_non_fungible(
+                donation_account.wallet_address,
+                NonFungibleGlobalId::new(base.trophy_resource_address, trophy_id.clone()),
+            )
+            .create_proof_from_auth_zone_of_non_fungibles(
+                base.trophy_resource_address,
+                vec![trophy_id.clone()],
+                "trophy_proof",
+            )
+            .call_method_with_name_lookup(collection_component, "donate_update", |lookup| {
+                (
+                    lookup.bucket("donation_amount"),
+                    lookup.proof("trophy_proof"),
+                    None::<String>,
+                )
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Split the trophy into one trophy per donation
+        let manifest = ManifestBuilder::new()
+            .withdraw_non_fungibles_from_account(
+                donation_account.wallet_address,
+                base.trophy_resource_address,
+                vec![trophy_id.clone()],
+            )
+            .take_all_from_worktop(base.trophy_resource_address, "trophy")
+            .call_method_with_name_lookup(base.repository_component, "split_trophy", |lookup| {
+                (lookup.bucket("trophy"), vec![vec![0u32], vec![1u32]])
+            })
+            .assert_worktop_contains(base.trophy_resource_address, dec!(2))
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let mut donated: Vec<Decimal> = base
+            .test_runner
+            .inspect_non_fungible_vault(trophy_vault[0])
+            .unwrap()
+            .1
+            .map(|id| {
+                let data: Trophy = base
+                    .test_runner
+                    .get_non_fungible_data(base.trophy_resource_address, id.clone());
+                assert_eq!(data.transactions.len(), 1);
+                data.donated
+            })
+            .collect();
+        donated.sort();
+
+        assert_eq!(donated, vec![dec!(100), dec!(200)]);
+    }
+
+    #[test]
+    fn split_trophy_success_mixed_resources() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_success_mixed_resources_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account, holding a stablecoin
+        let donation_account = new_account(&mut base.test_runner);
+        let stablecoin = base.test_runner.create_fungible_resource(
+            dec!(1000),
+            DIVISIBILITY_MAXIMUM,
+            donation_account.wallet_address,
+        );
+
+        // Accept the stablecoin as donation, and set its donation limits
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "add_accepted_resource",
+                manifest_args!(stablecoin),
+            )
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "set_donation_limits",
+                manifest_args!(
+                    stablecoin,
+                    Some(DonationLimits {
+                        minimum: dec!(1),
+                        maximum: None,
+                    })
+                ),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_success_mixed_resources_2",
+            vec![
+                NonFungibleGlobalId::from_public_key(&base.owner_account.public_key),
+                NonFungibleGlobalId::from_public_key(&creator_badge_account.public_key),
+            ],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Donate and mint trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_success_mixed_resources_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id = base
+            .test_runner
+            .inspect_non_fungible_vault(trophy_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        // Donate the stablecoin to the same trophy, which is not counted
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, stablecoin, dec!(200))
+            .take_from_worktop(stablecoin, dec!(200), "donation_amount")
+            .create_proof_from_account_of_non_fungible(
+                donation_account.wallet_address,
+                NonFungibleGlobalId::new(base.trophy_resource_address, trophy_id.clone()),
+            )
+            .create_proof_from_auth_zone_of_non_fungibles(
+                base.trophy_resource_address,
+                vec![trophy_id.clone()],
+                "trophy_proof",
+            )
+            .call_method_with_name_lookup(collection_component, "donate_update", |lookup| {
+                (
+                    lookup.bucket("donation_amount"),
+                    lookup.proof("trophy_proof"),
+                    None::<String>,
+                )
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_success_mixed_resources_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Split the trophy into one trophy per resource
+        let manifest = ManifestBuilder::new()
+            .withdraw_non_fungibles_from_account(
+                donation_account.wallet_address,
+                base.trophy_resource_address,
+                vec![trophy_id.clone()],
+            )
+            .take_all_from_worktop(base.trophy_resource_address, "trophy")
+            .call_method_with_name_lookup(base.repository_component, "split_trophy", |lookup| {
+                (lookup.bucket("trophy"), vec![vec![0u32], vec![1u32]])
+            })
+            .assert_worktop_contains(base.trophy_resource_address, dec!(2))
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_success_mixed_resources_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let mut donated: Vec<Decimal> = base
+            .test_runner
+            .inspect_non_fungible_vault(trophy_vault[0])
+            .unwrap()
+            .1
+            .map(|id| {
+                let data: Trophy = base
+                    .test_runner
+                    .get_non_fungible_data(base.trophy_resource_address, id.clone());
+                assert_eq!(data.transactions.len(), 1);
+                data.donated
+            })
+            .collect();
+        donated.sort();
+
+        // Only the donation in the counted resource counts towards the donated amount
+        assert_eq!(donated, vec![dec!(0), dec!(100)]);
+    }
+
+    #[test]
+    fn split_trophy_failure_partition() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_failure_partition_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Donate and mint trophy
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_failure_partition_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // A trophy with one transaction can not be split into two
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(
+                donation_account.wallet_address,
+                base.trophy_resource_address,
+                dec!(1),
+            )
+            .take_all_from_worktop(base.trophy_resource_address, "trophy")
+            .call_method_with_name_lookup(base.repository_component, "split_trophy", |lookup| {
+                (lookup.bucket("trophy"), vec![vec![0u32], vec![0u32]])
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "split_trophy_failure_partition_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+    }
+
+    #[test]
+    fn merge_creator_badges_success() {
+        let mut base = new_runner();
+
+        // Create two creator badges minted on different days, held in separate accounts until they
+        // are merged
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let first_badge_id =
+            mint_creator_badge_with_slug(&mut base, &creator_badge_account, "Kansuler", "kansuler");
+
+        base.test_runner
+            .advance_to_round_at_timestamp(Round::of(2), 1699142400000); // 2023-11-05
+
+        let second_account = new_account(&mut base.test_runner);
+        let second_badge_id =
+            mint_creator_badge_with_slug(&mut base, &second_account, "Kansuler", "kansuler-2");
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Create a collection component with the second badge
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                second_account.wallet_address,
+                second_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_creator_badges_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &second_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Donate to the collection of the second badge
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_creator_badges_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // List a perk with the second badge, and redeem it with the thanks tokens of the donation
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                second_account.wallet_address,
+                second_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(base.repository_component, "add_perk", |lookup| {
+                (
+                    lookup.proof("creator_badge_proof"),
+                    "Signed poster",
+                    "A poster signed by Kansuler",
+                    dec!(50),
+                    1u64,
+                )
+            });
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_creator_badges_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &second_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(
+                donation_account.wallet_address,
+                base.thanks_token_resource_address,
+                dec!(50),
+            )
+            .take_all_from_worktop(base.thanks_token_resource_address, "thanks")
+            .call_method_with_name_lookup(base.repository_component, "redeem_perk", |lookup| {
+                (1u64, lookup.bucket("thanks"))
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_creator_badges_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Merge the creator badges
+        let manifest = ManifestBuilder::new()
+            .withdraw_non_fungibles_from_account(
+                creator_badge_account.wallet_address,
+                base.creator_badge_resource_address,
+                vec![first_badge_id.local_id().clone()],
+            )
+            .withdraw_non_fungibles_from_account(
+                second_account.wallet_address,
+                base.creator_badge_resource_address,
+                vec![second_badge_id.local_id().clone()],
+            )
+            .take_all_from_worktop(base.creator_badge_resource_address, "creator_badges")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "merge_creator_badges",
+                |lookup| (lookup.bucket("creator_badges"),),
+            )
+            .assert_worktop_contains(base.creator_badge_resource_address, dec!(1))
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_creator_badges_success_5",
+            vec![
+                NonFungibleGlobalId::from_public_key(&creator_badge_account.public_key),
+                NonFungibleGlobalId::from_public_key(&second_account.public_key),
+            ],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        let creator_data: Creator = base.test_runner.get_non_fungible_data(
+            base.creator_badge_resource_address,
+            first_badge_id.local_id().clone(),
+        );
+
+        assert_eq!(creator_data.funded, dec!(100));
+        assert_eq!(creator_data.created, "2023-11-04");
+
+        // The collection is owned by the remaining badge, and the slug of the merged badge is
+        // released
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                base.repository_component,
+                "get_collections",
+                manifest_args!(first_badge_id.clone()),
+            )
+            .call_method(
+                base.repository_component,
+                "get_creator_slug_owner",
+                manifest_args!("kansuler-2"),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_creator_badges_success_6",
+            vec![],
+            true,
+        );
+
+        let result = receipt.expect_commit_success();
+
+        let collections: Vec<ComponentAddress> = result.output(0);
+        assert_eq!(collections, vec![collection_component]);
+
+        let slug_owner: Option<NonFungibleGlobalId> = result.output(1);
+        assert!(slug_owner.is_none());
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                first_badge_id.clone(),
+            )
+            .call_method(collection_component, "withdraw_donations", manifest_args!())
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_creator_badges_success_7",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // The merged badge resolves to the remaining badge
+        let manifest = ManifestBuilder::new().call_method(
+            base.repository_component,
+            "resolve_creator",
+            manifest_args!(second_badge_id),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_creator_badges_success_8",
+            vec![],
+            true,
+        );
+
+        let resolved: NonFungibleGlobalId = receipt.expect_commit_success().output(0);
+        assert_eq!(resolved, first_badge_id);
+
+        // Trophies minted before the merge can still be updated
+        let trophy_vault = base.test_runner.get_component_vaults(
+            donation_account.wallet_address,
+            base.trophy_resource_address,
+        );
+
+        let trophy_id = base
+            .test_runner
+            .inspect_non_fungible_vault(trophy_vault[0])
+            .unwrap()
+            .1
+            .next()
+            .unwrap();
+
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .create_proof_from_account_of_non_fungible(
+                donation_account.wallet_address,
+                NonFungibleGlobalId::new(base.trophy_resource_address, trophy_id.clone()),
+            )
+            .create_proof_from_auth_zone_of_non_fungibles(
+                base.trophy_resource_address,
+                vec![trophy_id],
+                "proof",
+            )
+            .call_method_with_name_lookup(collection_component, "donate_update", |lookup| {
+                (
+                    lookup.bucket("donation_amount"),
+                    lookup.proof("proof"),
+                    None::<String>,
+                )
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_creator_badges_success_9",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // The perk and its outstanding redemption are handed over to the remaining badge
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                base.repository_component,
+                "get_perks",
+                manifest_args!(first_badge_id.clone()),
+            )
+            .call_method(
+                base.repository_component,
+                "get_outstanding_redemptions",
+                manifest_args!(first_badge_id.clone()),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_creator_badges_success_10",
+            vec![],
+            true,
+        );
+
+        let result = receipt.expect_commit_success();
+
+        let perks: Vec<u64> = result.output(0);
+        assert_eq!(perks, vec![1]);
+
+        let outstanding: Vec<NonFungibleLocalId> = result.output(1);
+        assert_eq!(outstanding.len(), 1);
+
+        let receipt_id = outstanding[0].clone();
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                first_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "fulfil_redemption",
+                |lookup| (lookup.proof("creator_badge_proof"), receipt_id.clone()),
+            )
+            .call_method(
+                base.repository_component,
+                "get_outstanding_redemptions",
+                manifest_args!(first_badge_id),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "merge_creator_badges_success_11",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let outstanding: Vec<NonFungibleLocalId> = receipt.expect_commit_success().output(3);
+        assert!(outstanding.is_empty());
+    }
+
+    #[test]
+    fn redeem_perk_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "redeem_perk_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // List a perk with a stock of 1
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(base.repository_component, "add_perk", |lookup| {
+                (
+                    lookup.proof("creator_badge_proof"),
+                    "Signed poster",
+                    "A poster signed by Kansuler",
+                    dec!(50),
+                    1u64,
+                )
+            });
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "redeem_perk_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Donate to earn thanks tokens
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "redeem_perk_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Redeem the perk, the remaining thanks tokens are returned
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(
+                donation_account.wallet_address,
+                base.thanks_token_resource_address,
+                dec!(100),
+            )
+            .take_all_from_worktop(base.thanks_token_resource_address, "thanks")
+            .call_method_with_name_lookup(base.repository_component, "redeem_perk", |lookup| {
+                (1u64, lookup.bucket("thanks"))
+            })
+            .assert_worktop_contains(base.redemption_receipt_resource_address, dec!(1))
+            .assert_worktop_contains(base.thanks_token_resource_address, dec!(50))
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "redeem_perk_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // The perk is out of stock
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(
+                donation_account.wallet_address,
+                base.thanks_token_resource_address,
+                dec!(50),
+            )
+            .take_all_from_worktop(base.thanks_token_resource_address, "thanks")
+            .call_method_with_name_lookup(base.repository_component, "redeem_perk", |lookup| {
+                (1u64, lookup.bucket("thanks"))
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "redeem_perk_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // The creator fulfils the outstanding redemption
+        let manifest = ManifestBuilder::new().call_method(
+            base.repository_component,
+            "get_outstanding_redemptions",
+            manifest_args!(creator_badge_badge_id.clone()),
+        );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "redeem_perk_success_6",
+            vec![],
+            true,
+        );
+
+        let outstanding: Vec<NonFungibleLocalId> = receipt.expect_commit_success().output(0);
+        assert_eq!(outstanding.len(), 1);
+
+        let receipt_id = outstanding[0].clone();
+
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "fulfil_redemption",
+                |lookup| (lookup.proof("creator_badge_proof"), receipt_id.clone()),
+            )
+            .call_method(
+                base.repository_component,
+                "get_outstanding_redemptions",
+                manifest_args!(creator_badge_badge_id),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "redeem_perk_success_7",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let outstanding: Vec<NonFungibleLocalId> = receipt.expect_commit_success().output(3);
+        assert!(outstanding.is_empty());
+
+        let receipt_data: RedemptionReceipt = base
+            .test_runner
+            .get_non_fungible_data(base.redemption_receipt_resource_address, receipt_id);
+
+        assert!(receipt_data.fulfilled);
+        assert_eq!(receipt_data.perk_id, 1);
+    }
+
+    #[test]
+    fn approve_milestone_success() {
+        let mut base = new_runner();
+
+        // Create an component admin account
+        let creator_badge_account = new_account(&mut base.test_runner);
+        let creator_badge_badge_id: NonFungibleGlobalId;
+        {
+            creator_badge_badge_id = mint_creator_badge(&mut base, &creator_badge_account);
+        }
+
+        // Create a collection component
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .pop_from_auth_zone("creator_badge_proof")
+            .call_method_with_name_lookup(
+                base.repository_component,
+                "new_collection_component",
+                |lookup| {
+                    (
+                        lookup.proof("creator_badge_proof"),
+                        "Trophy name",
+                        "Kansulers trophy",
+                        None::<FundingGoal>,
+                    )
+                },
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "approve_milestone_success_1",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        let collection_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        // Vest the donations by two milestones
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                collection_component,
+                "set_vesting",
+                manifest_args!(Vesting::Milestones(vec![
+                    Milestone {
+                        description: "Record the album".to_string(),
+                        share: 3000u16,
+                        approved: false
+                    },
+                    Milestone {
+                        description: "Release the album".to_string(),
+                        share: 7000u16,
+                        approved: false
+                    }
+                ])),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "approve_milestone_success_2",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // Create donation account
+        let donation_account = new_account(&mut base.test_runner);
+
+        // Donate 100 XRD, 96 XRD is locked after the fee
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(donation_account.wallet_address, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(100), "donation_amount")
+            .call_method_with_name_lookup(collection_component, "donate_mint", |lookup| {
+                (lookup.bucket("donation_amount"), None::<String>)
+            })
+            .deposit_batch(donation_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "approve_milestone_success_3",
+            vec![NonFungibleGlobalId::from_public_key(
+                &donation_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        // The creator can not approve their own milestone
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "approve_milestone",
+                manifest_args!(collection_component, 0u32),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "approve_milestone_success_4",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_failure();
+
+        // The repository admin approves the first milestone
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                base.owner_account.wallet_address,
+                base.repository_owner_badge_global_id.clone(),
+            )
+            .call_method(
+                base.repository_component,
+                "approve_milestone",
+                manifest_args!(collection_component, 0u32),
+            )
+            .call_method(
+                collection_component,
+                "get_vesting_balance",
+                manifest_args!(XRD),
+            );
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "approve_milestone_success_5",
+            vec![NonFungibleGlobalId::from_public_key(
+                &base.owner_account.public_key,
+            )],
+            true,
+        );
+
+        let balance: (Decimal, Decimal) = receipt.expect_commit_success().output(2);
+        assert_eq!(balance, (dec!(67.2), dec!(28.8)));
+
+        // The creator withdraws the unlocked donations
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_non_fungible(
+                creator_badge_account.wallet_address,
+                creator_badge_badge_id,
+            )
+            .call_method(collection_component, "withdraw_donations", manifest_args!())
+            .deposit_batch(creator_badge_account.wallet_address);
+
+        let receipt = execute_manifest(
+            &mut base.test_runner,
+            manifest,
+            "approve_milestone_success_6",
+            vec![NonFungibleGlobalId::from_public_key(
+                &creator_badge_account.public_key,
+            )],
+            true,
+        );
+
+        receipt.expect_commit_success();
+
+        assert_eq!(
+            base.test_runner
+                .get_component_balance(creator_badge_account.wallet_address, XRD),
+            dec!(10028.8)
         );
     }
 }